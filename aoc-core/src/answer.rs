@@ -0,0 +1,243 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Answer to one part of a puzzle.
+///
+/// Integers are normalized on construction: non-negative values are always
+/// [`Answer::Unsigned`], whatever type they came from, so `42u8` and `42i64`
+/// compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    /// Always negative.
+    Signed(i128),
+    Text(String),
+    /// Multi-line answer, e.g. letters drawn in ASCII art.
+    Art(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Art(_))
+    }
+}
+
+macro_rules! from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Unsigned(n as u128)
+            }
+        })*
+    };
+}
+
+macro_rules! from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::from(n as i128)
+            }
+        })*
+    };
+}
+
+from_unsigned!(u8, u16, u32, u64, u128, usize);
+from_signed!(i8, i16, i32, i64, isize);
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        match u128::try_from(n) {
+            Ok(n) => Self::Unsigned(n),
+            Err(_) => Self::Signed(n),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.contains('\n') {
+            Self::Art(s)
+        } else {
+            Self::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::from(s.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Integers become numeric answers, anything else is kept as text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let answer = if let Ok(n) = s.parse::<u128>() {
+            Self::Unsigned(n)
+        } else if let Ok(n) = s.parse::<i128>() {
+            Self::from(n)
+        } else {
+            Self::from(s)
+        };
+        Ok(answer)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsigned(n) => n.fmt(f),
+            Self::Signed(n) => n.fmt(f),
+            Self::Text(s) => s.fmt(f),
+            Self::Art(s) => f.write_str(s),
+        }
+    }
+}
+
+/// Integers that fit JSON numbers are written as numbers, everything else
+/// (including integers past `u64` / `i64`) as strings.
+///
+/// Strings that read as integers load back as numbers, so text that looks
+/// like one, e.g. `"0123"`, is written as `{"text": "0123"}` instead.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Unsigned(n) => match u64::try_from(*n) {
+                Ok(n) => serializer.serialize_u64(n),
+                Err(_) => serializer.collect_str(n),
+            },
+            Self::Signed(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.collect_str(n),
+            },
+            Self::Text(s) if looks_numeric(s) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(TEXT_KEY, s)?;
+                map.end()
+            }
+            Self::Text(s) | Self::Art(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+/// Key of the tagged form of text answers that look like integers.
+const TEXT_KEY: &str = "text";
+
+fn looks_numeric(s: &str) -> bool {
+    let Ok(answer) = s.parse();
+    matches!(answer, Answer::Unsigned(_) | Answer::Signed(_))
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl<'de> de::Visitor<'de> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(r#"an integer, a string or {"text": "..."}"#)
+            }
+
+            fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+                Ok(Answer::from(n))
+            }
+
+            fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+                Ok(Answer::from(n))
+            }
+
+            fn visit_u128<E: de::Error>(self, n: u128) -> Result<Answer, E> {
+                Ok(Answer::from(n))
+            }
+
+            fn visit_i128<E: de::Error>(self, n: i128) -> Result<Answer, E> {
+                Ok(Answer::from(n))
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+                let Ok(answer) = s.parse();
+                Ok(answer)
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Answer, A::Error> {
+                let text = match map.next_entry::<String, String>()? {
+                    Some((key, text)) if key == TEXT_KEY => text,
+                    _ => return Err(de::Error::invalid_value(de::Unexpected::Map, &self)),
+                };
+                if map.next_key::<String>()?.is_some() {
+                    return Err(de::Error::invalid_length(2, &self));
+                }
+                Ok(Answer::Text(text))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_normalize_integers() {
+        assert_eq!(Answer::from(42u8), Answer::from(42i64));
+        assert_eq!(Answer::Unsigned(42), Answer::from(42isize));
+        assert_eq!(Answer::Signed(-7), Answer::from(-7i32));
+        assert_eq!(Answer::Unsigned(u128::MAX), Answer::from(u128::MAX));
+    }
+
+    #[test]
+    fn test_text_and_art() {
+        assert_eq!(Answer::Text("ABCDEF".into()), Answer::from("ABCDEF"));
+        assert!(Answer::from("#..#\n####\n#..#").is_multiline());
+    }
+
+    #[rstest]
+    #[case(Answer::from(288u32), "288")]
+    #[case(Answer::from(-3i64), "-3")]
+    #[case(Answer::from(u128::MAX), "\"340282366920938463463374607431768211455\"")]
+    #[case(
+        Answer::from(i128::MIN),
+        "\"-170141183460469231731687303715884105728\""
+    )]
+    #[case(Answer::from("FJL"), "\"FJL\"")]
+    #[case(Answer::Text("0123".into()), r#"{"text":"0123"}"#)]
+    #[case(Answer::Text("-7".into()), r#"{"text":"-7"}"#)]
+    #[case(Answer::from(".#\n#."), "\".#\\n#.\"")]
+    fn test_json_round_trip(#[case] answer: Answer, #[case] json: &str) {
+        assert_eq!(json, serde_json::to_string(&answer).unwrap());
+        assert_eq!(answer, serde_json::from_str::<Answer>(json).unwrap());
+    }
+
+    #[test]
+    fn test_numeric_strings_compare_as_numbers() {
+        assert_eq!(Answer::from(55834u32), "55834".parse().unwrap());
+        assert_eq!(
+            Answer::from(55834u32),
+            serde_json::from_str("\"55834\"").unwrap()
+        );
+
+        // only the tagged form keeps text that looks like a number
+        assert!(serde_json::from_str::<Answer>(r#"{"txt": "0123"}"#).is_err());
+        assert!(serde_json::from_str::<Answer>(r#"{"text": "1", "n": "2"}"#).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("-3", Answer::from(-3i8).to_string());
+        assert_eq!("#.\n.#", Answer::from("#.\n.#").to_string());
+        assert_eq!("   42", format!("{:>5}", Answer::from(42u8)));
+    }
+}
@@ -0,0 +1,130 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{input::workspace_dir, Answer, Part};
+
+/// Checked-in answers file, relative to the workspace root.
+pub const ANSWERS_FILE: &str = "answers.json";
+
+/// Reads JSON from `path`, taking a missing file as `T::default()`.
+pub(crate) fn load_json_or_default<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match fs::read_to_string(path) {
+        Ok(json) => Ok(serde_json::from_str(&json)?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Short, stable fingerprint of a puzzle input.
+///
+/// Trailing whitespace is ignored, so an input saved with or without a final
+/// newline maps to the same answers.
+pub fn fingerprint(input: &str) -> String {
+    let digest = Sha256::digest(input.trim_end().as_bytes());
+    digest[..8].iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Accepted answer for one part of one day, for one particular input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub answer: Answer,
+}
+
+impl KnownAnswer {
+    fn key(&self) -> (u8, Part, &str) {
+        (self.day, self.part, &self.input)
+    }
+}
+
+/// Every known answer, kept sorted by day, part and input fingerprint.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(Vec<KnownAnswer>);
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        workspace_dir().join(ANSWERS_FILE)
+    }
+
+    /// Loads answers from `path`; a missing file means nothing is known yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        load_json_or_default(path)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut json = serde_json::to_string_pretty(self)?;
+        json.push('\n');
+        fs::write(path, json)
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&Answer> {
+        self.0
+            .iter()
+            .find(|known| known.day == day && known.part == part && known.input == input)
+            .map(|known| &known.answer)
+    }
+
+    /// Records `answer`, replacing a previous answer for the same key.
+    pub fn insert(&mut self, day: u8, part: Part, input: &str, answer: Answer) {
+        let known = KnownAnswer {
+            day,
+            part,
+            input: input.to_string(),
+            answer,
+        };
+        self.0.retain(|other| other.key() != known.key());
+        self.0.push(known);
+        self.0.sort_by(|a, b| a.key().cmp(&b.key()));
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(16, fingerprint("1abc2").len());
+        assert_eq!(fingerprint("1abc2\n"), fingerprint("1abc2"));
+        assert_ne!(fingerprint("1abc2"), fingerprint("1abc3"));
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut answers = Answers::default();
+        answers.insert(5, Part::Two, "cafe", 46u64.into());
+        answers.insert(1, Part::One, "cafe", 142u32.into());
+        answers.insert(5, Part::Two, "cafe", 47u64.into());
+
+        assert_eq!(2, answers.len());
+        assert_eq!(Some(&Answer::from(47u8)), answers.get(5, Part::Two, "cafe"));
+        assert_eq!(None, answers.get(5, Part::One, "cafe"));
+        assert_eq!(1, answers.0[0].day);
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(6, Part::One, "beef", 288usize.into());
+
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(r#"[{"day":6,"part":1,"input":"beef","answer":288}]"#, json);
+        assert_eq!(answers, serde_json::from_str(&json).unwrap());
+    }
+}
@@ -0,0 +1,85 @@
+use std::{io, sync::Arc};
+
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+use crate::Part;
+
+/// Failures every day can run into.
+///
+/// Each day's `AocError` wraps this in a transparent `Core` variant next to
+/// its own domain errors, which use the `day_XX::` code namespace.
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(#[from] ParseError),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ParseLines(#[from] ParseErrors),
+
+    #[error("invalid puzzle data: {0}")]
+    #[diagnostic(code(aoc::invalid_data))]
+    InvalidData(String),
+
+    #[error("day {day:02} {part} is not solved yet")]
+    #[diagnostic(code(aoc::unsolved))]
+    Unsolved { day: u8, part: Part },
+}
+
+/// Input that doesn't match the grammar of a day, pointing at where the
+/// parser gave up.
+#[derive(Error, Diagnostic, Debug)]
+#[error("cannot parse {what}")]
+#[diagnostic(code(aoc::parse_error))]
+pub struct ParseError {
+    /// What was being parsed, e.g. `"almanac"`.
+    pub what: &'static str,
+    pub expected: String,
+    #[source_code]
+    pub src: Arc<str>,
+    #[label("expected {expected}")]
+    pub span: SourceSpan,
+    /// Grammar rules the parser was inside of, outermost first.
+    #[help]
+    pub help: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(
+        what: &'static str,
+        src: impl Into<Arc<str>>,
+        span: impl Into<SourceSpan>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            what,
+            expected: expected.into(),
+            src: src.into(),
+            span: span.into(),
+            help: None,
+        }
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
+
+/// Every line of an input that failed to parse, reported together.
+#[derive(Error, Diagnostic, Debug)]
+#[error("cannot parse {} of {lines} {what} lines", .errors.len())]
+#[diagnostic(code(aoc::parse_errors))]
+pub struct ParseErrors {
+    pub what: &'static str,
+    /// Number of lines in the input, good or bad.
+    pub lines: usize,
+    #[related]
+    pub errors: Vec<ParseError>,
+}
@@ -0,0 +1,266 @@
+//! Worked examples from the puzzle descriptions.
+//!
+//! `aoc examples` pulls them out of the puzzle pages cached by
+//! `scripts/get-aoc-input.rs` into `day-XX/examples/`, laid out like the real
+//! inputs: `input.txt` shared by both parts, `input2.txt` when part 2 comes
+//! with an example of its own, and the highlighted answers in `answers.json`.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::load_json_or_default,
+    input::{day_input, workspace_dir, Source, INPUT_FILE},
+    Answer, Error, Part,
+};
+
+/// Directory of a day crate holding its examples.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Expected answers of the examples, relative to [`EXAMPLES_DIR`].
+pub const ANSWERS_FILE: &str = "answers.json";
+
+/// Example input of one part, with the answer the puzzle text gives for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub input: String,
+    /// `None` until the answer was extracted, e.g. part 2 before solving part 1.
+    pub answer: Option<Answer>,
+}
+
+impl Example {
+    /// Loads the example of `part` from `day-XX/examples/` in the workspace.
+    pub fn load(day: u8, part: Part) -> Result<Self, Error> {
+        Self::load_from(&examples_dir(day), part)
+    }
+
+    fn load_from(dir: &Path, part: Part) -> Result<Self, Error> {
+        let input = Source::File(day_input(dir, part)).read()?;
+        let answers = ExampleAnswers::load(&dir.join(ANSWERS_FILE))?;
+
+        Ok(Self {
+            input,
+            answer: answers.get(part).cloned(),
+        })
+    }
+}
+
+/// `day-XX/examples/` in the workspace.
+pub fn examples_dir(day: u8) -> PathBuf {
+    workspace_dir()
+        .join(format!("day-{day:02}"))
+        .join(EXAMPLES_DIR)
+}
+
+/// Contents of `answers.json`.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExampleAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+impl ExampleAnswers {
+    /// Loads answers from `path`; a missing file means no answer is known.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        load_json_or_default(path)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// What a puzzle page shows for one part.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PageExample {
+    /// First `<pre><code>` block of the part.
+    pub input: Option<String>,
+    /// Last `<code><em>` of the part, which is where the example answer goes.
+    pub answer: Option<String>,
+}
+
+/// Pulls the example of every part out of a puzzle page.
+///
+/// Each part is an `<article class="day-desc">`; part 2 only shows up on
+/// the page once part 1 is solved.
+pub fn extract(html: &str) -> Vec<PageExample> {
+    elements(html, r#"<article class="day-desc">"#, "</article>")
+        .map(|article| PageExample {
+            input: elements(article, "<pre><code>", "</code></pre>")
+                .next()
+                .map(text),
+            answer: elements(article, "<code><em>", "</em></code>")
+                .last()
+                .map(text),
+        })
+        .collect()
+}
+
+/// Writes the examples of a puzzle page into `dir`, returning the files written.
+///
+/// Part 2 only gets its own `input2.txt` if its example differs from part 1.
+pub fn save(dir: &Path, examples: &[PageExample]) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    let mut write = |name: &str, contents: &str| {
+        let path = dir.join(name);
+        fs::write(&path, contents)?;
+        written.push(path);
+        io::Result::Ok(())
+    };
+
+    let (part1, part2) = match examples {
+        [] => return Ok(written),
+        [part1] => (part1, None),
+        [part1, part2, ..] => (part1, Some(part2)),
+    };
+
+    if let Some(input) = &part1.input {
+        write(INPUT_FILE, input)?;
+    }
+    if let Some(input) = part2.and_then(|part2| part2.input.as_ref()) {
+        if part1.input.as_ref() != Some(input) {
+            write("input2.txt", input)?;
+        }
+    }
+
+    let answer = |answer: &str| answer.parse().unwrap_or_else(|never| match never {});
+    let answers = ExampleAnswers {
+        part1: part1.answer.as_deref().map(answer),
+        part2: part2.and_then(|part2| part2.answer.as_deref()).map(answer),
+    };
+    let mut json = serde_json::to_string_pretty(&answers)?;
+    json.push('\n');
+    write(ANSWERS_FILE, &json)?;
+
+    Ok(written)
+}
+
+/// Contents of every `open ... close` element in `html`, in order.
+fn elements<'a>(
+    html: &'a str,
+    open: &'static str,
+    close: &'static str,
+) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)? + open.len();
+        let len = rest[start..].find(close)?;
+        let element = &rest[start..start + len];
+        rest = &rest[start + len + close.len()..];
+        Some(element)
+    })
+}
+
+/// Text of an HTML fragment: tags dropped, entities decoded.
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 7: Camel Cards ---</h2>
+<p>For example:</p>
+<pre><code>32T3K 765
+T55J5 684
+KK677 28
+</code></pre>
+<p>Sort by <em>strength</em> &amp; multiply by <code>bid</code>: <code><em>6440</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>250602641</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Now <code>J</code> cards are jokers, the total is now <code><em>5905</em></code>.</p>
+</article>
+</main>"#;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-core-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE);
+
+        assert_eq!(2, examples.len());
+        assert_eq!(
+            Some("32T3K 765\nT55J5 684\nKK677 28\n"),
+            examples[0].input.as_deref()
+        );
+        assert_eq!(Some("6440"), examples[0].answer.as_deref());
+        assert_eq!(None, examples[1].input);
+        assert_eq!(Some("5905"), examples[1].answer.as_deref());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            "a < b && <em>",
+            text("a &lt; b <em>&amp;&amp;</em> &lt;em&gt;")
+        );
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = temp_dir("examples");
+        save(&dir, &extract(PAGE)).unwrap();
+
+        let part1 = Example::load_from(&dir, Part::One).unwrap();
+        let part2 = Example::load_from(&dir, Part::Two).unwrap();
+        assert_eq!(part1.input, part2.input);
+        assert_eq!(Some(Answer::from(6440u32)), part1.answer);
+        assert_eq!(Some(Answer::from(5905u32)), part2.answer);
+        assert!(!dir.join("input2.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_save_part2_input() {
+        let dir = temp_dir("examples-part2");
+        let examples = [
+            PageExample {
+                input: Some("1abc2\n".into()),
+                answer: Some("12".into()),
+            },
+            PageExample {
+                input: Some("two1nine\n".into()),
+                answer: None,
+            },
+        ];
+        save(&dir, &examples).unwrap();
+
+        let part2 = Example::load_from(&dir, Part::Two).unwrap();
+        assert_eq!("two1nine\n", part2.input);
+        assert_eq!(None, part2.answer);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
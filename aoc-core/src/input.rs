@@ -0,0 +1,147 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::Part;
+
+/// Environment variable overriding the default input location, mostly
+/// useful for benches, which can't take `--input`.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `$AOC_INPUT` if set, otherwise the input of `day` in the workspace.
+    pub fn lookup(day: u8, part: Part) -> Self {
+        match std::env::var_os(INPUT_VAR) {
+            Some(path) => Self::from(PathBuf::from(path)),
+            None => Self::File(workspace_input(day, part)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Self::File(path) => std::fs::read_to_string(path).map_err(|err| {
+                io::Error::new(err.kind(), format!("cannot read {}: {err}", path.display()))
+            }),
+            Self::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+}
+
+/// Root of this workspace, as seen when `aoc-core` was compiled.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc-core lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Event the puzzles are from, unless told otherwise.
+pub const YEAR: u16 = 2023;
+
+/// Downloads of `scripts/get-aoc-input.rs`, relative to the workspace root.
+pub const CACHE_DIR: &str = ".cache";
+
+/// Where the fetcher keeps the input and puzzle page of `day`.
+pub fn cache_dir(year: u16, day: u8) -> PathBuf {
+    workspace_dir()
+        .join(CACHE_DIR)
+        .join(year.to_string())
+        .join(format!("day-{day:02}"))
+}
+
+/// Puzzle input shared by both parts of a day.
+pub const INPUT_FILE: &str = "input.txt";
+
+/// `day-XX/input.txt` relative to the root of this workspace.
+fn workspace_input(day: u8, part: Part) -> PathBuf {
+    day_input(&workspace_dir().join(format!("day-{day:02}")), part)
+}
+
+/// `inputN.txt` in `dir` if `part` has an input of its own, `input.txt` otherwise.
+pub(crate) fn day_input(dir: &Path, part: Part) -> PathBuf {
+    let part_input = dir.join(format!("input{}.txt", part as u8));
+    if part_input.exists() {
+        part_input
+    } else {
+        dir.join(INPUT_FILE)
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Self::Stdin
+        } else {
+            Self::File(path)
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    /// `-` means stdin, anything else is a path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(PathBuf::from(s)))
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(Ok(Source::Stdin), "-".parse());
+        assert_eq!(
+            Ok(Source::File(PathBuf::from("day-05/edge.txt"))),
+            "day-05/edge.txt".parse()
+        );
+    }
+
+    #[test]
+    fn test_workspace_input() {
+        let path = workspace_input(5, Part::Two);
+        assert!(path.ends_with("day-05/input.txt"));
+    }
+
+    #[test]
+    fn test_part_input_overrides_day_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("input2.txt"), "").unwrap();
+
+        assert_eq!(dir.join("input.txt"), day_input(&dir, Part::One));
+        assert_eq!(dir.join("input2.txt"), day_input(&dir, Part::Two));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = Source::File(PathBuf::from("does/not/exist.txt"));
+        let err = source.read().unwrap_err();
+        assert_eq!(io::ErrorKind::NotFound, err.kind());
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,13 @@
+pub mod answer;
+pub mod answers;
+pub mod error;
+pub mod examples;
+pub mod input;
+pub mod parsing;
+pub mod progress;
+pub mod runner;
+pub mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, ParseError, ParseErrors};
+pub use solution::{Part, Puzzle, Solution};
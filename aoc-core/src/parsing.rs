@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use nom::Parser;
+use nom_locate::LocatedSpan;
+use nom_supreme::{
+    error::{BaseErrorKind, GenericErrorTree, StackContext},
+    final_parser::final_parser,
+};
+
+use crate::{
+    error::{ParseError, ParseErrors},
+    Error,
+};
+
+/// Input of every day parser: the text plus its position in the puzzle input.
+pub type Span<'a> = LocatedSpan<&'a str>;
+
+pub type ErrorTree<'a> = nom_supreme::error::ErrorTree<Span<'a>>;
+
+pub type IResult<'a, T> = nom::IResult<Span<'a>, T, ErrorTree<'a>>;
+
+/// Runs `parser` over the whole of `input`.
+///
+/// A failure becomes a [`ParseError`] pointing at the furthest position any
+/// branch of the grammar got to, with everything expected there.
+pub fn parse<'a, T>(
+    what: &'static str,
+    input: &'a str,
+    parser: impl Parser<Span<'a>, T, ErrorTree<'a>>,
+) -> Result<T, Error> {
+    final_parser(parser)(Span::new(input))
+        .map_err(|tree: ErrorTree| from_tree(what, input.into(), 0, &tree).into())
+}
+
+/// Runs `parser` over every line of `input`.
+///
+/// All malformed lines are reported at once: a single bad line as a plain
+/// [`ParseError`], several as a [`ParseErrors`] listing each of them.
+pub fn parse_lines<'a, T>(
+    what: &'static str,
+    input: &'a str,
+    parser: impl Parser<Span<'a>, T, ErrorTree<'a>>,
+) -> Result<Vec<T>, Error> {
+    parse_lines_partial(what, input, parser).into_result()
+}
+
+/// The lines that did parse, next to the ones that didn't.
+#[derive(Debug)]
+pub struct Partial<T> {
+    pub parsed: Vec<T>,
+    pub errors: Option<ParseErrors>,
+}
+
+impl<T> Partial<T> {
+    pub fn into_result(self) -> Result<Vec<T>, Error> {
+        match self.errors {
+            None => Ok(self.parsed),
+            Some(mut errors) if errors.errors.len() == 1 => Err(errors.errors.remove(0).into()),
+            Some(errors) => Err(errors.into()),
+        }
+    }
+}
+
+/// Runs `parser` over every line of `input`, keeping going after failures.
+///
+/// Errors point into the whole input rather than into the failing line.
+pub fn parse_lines_partial<'a, T>(
+    what: &'static str,
+    input: &'a str,
+    parser: impl Parser<Span<'a>, T, ErrorTree<'a>>,
+) -> Partial<T> {
+    let mut parser = final_parser(parser);
+    let mut src: Option<Arc<str>> = None;
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+    let mut lines = 0;
+
+    for line in input.lines() {
+        lines += 1;
+        match parser(Span::new(line)) {
+            Ok(item) => parsed.push(item),
+            Err(tree) => {
+                let tree: ErrorTree = tree;
+                let src = src.get_or_insert_with(|| input.into());
+                let line_offset = line.as_ptr() as usize - input.as_ptr() as usize;
+                errors.push(from_tree(what, Arc::clone(src), line_offset, &tree));
+            }
+        }
+    }
+
+    Partial {
+        parsed,
+        errors: (!errors.is_empty()).then_some(ParseErrors {
+            what,
+            lines,
+            errors,
+        }),
+    }
+}
+
+/// One leaf of an error tree.
+struct Failure {
+    offset: usize,
+    expected: String,
+    contexts: Vec<&'static str>,
+}
+
+fn failures(tree: &ErrorTree, contexts: &mut Vec<&'static str>, out: &mut Vec<Failure>) {
+    match tree {
+        GenericErrorTree::Base { location, kind } => out.push(Failure {
+            offset: location.location_offset(),
+            expected: match kind {
+                BaseErrorKind::Expected(expectation) => expectation.to_string(),
+                BaseErrorKind::Kind(kind) => kind.description().to_lowercase(),
+                BaseErrorKind::External(err) => err.to_string(),
+            },
+            contexts: contexts.clone(),
+        }),
+        GenericErrorTree::Stack {
+            base,
+            contexts: stack,
+        } => {
+            let depth = contexts.len();
+            // the stack lists the innermost context first
+            contexts.extend(stack.iter().rev().filter_map(|(_, context)| match context {
+                StackContext::Context(context) => Some(*context),
+                StackContext::Kind(_) => None,
+            }));
+            failures(base, contexts, out);
+            contexts.truncate(depth);
+        }
+        GenericErrorTree::Alt(siblings) => {
+            for sibling in siblings {
+                failures(sibling, contexts, out);
+            }
+        }
+    }
+}
+
+fn from_tree(
+    what: &'static str,
+    src: Arc<str>,
+    line_offset: usize,
+    tree: &ErrorTree,
+) -> ParseError {
+    let mut leaves = Vec::new();
+    failures(tree, &mut Vec::new(), &mut leaves);
+
+    let furthest = leaves.iter().map(|leaf| leaf.offset).max().unwrap_or(0);
+    let leaves = leaves
+        .into_iter()
+        .filter(|leaf| leaf.offset == furthest)
+        .collect::<Vec<_>>();
+
+    let mut expected = Vec::new();
+    for leaf in &leaves {
+        if !expected.contains(&leaf.expected) {
+            expected.push(leaf.expected.clone());
+        }
+    }
+
+    let offset = line_offset + furthest;
+    let len = src[offset..].chars().next().map_or(0, char::len_utf8);
+    let error = ParseError::new(what, src, (offset, len), expected.join(" or "));
+
+    match leaves.first() {
+        Some(leaf) if !leaf.contexts.is_empty() => {
+            error.with_help(format!("while parsing {}", leaf.contexts.join(" > ")))
+        }
+        _ => error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        branch::alt,
+        character::complete::u32,
+        sequence::{preceded, separated_pair},
+    };
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    use super::*;
+
+    fn game(input: Span) -> IResult<(u32, u32)> {
+        separated_pair(
+            preceded(tag("Game "), u32).context("game id"),
+            tag(": "),
+            alt((tag("red"), tag("blue")))
+                .precedes(tag(" "))
+                .precedes(u32)
+                .context("cubes"),
+        )(input)
+    }
+
+    fn parse_error(result: Result<impl std::fmt::Debug, Error>) -> ParseError {
+        match result {
+            Err(Error::Parse(err)) => err,
+            other => panic!("expected a parse error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!((13, 7), parse("game", "Game 13: red 7", game).unwrap());
+
+        let err = parse_error(parse("game", "Game x: red 7", game));
+        assert_eq!("game", err.what);
+        assert_eq!(5, err.span.offset());
+        assert_eq!(1, err.span.len());
+        assert_eq!("an ascii digit", err.expected);
+        assert_eq!(Some("while parsing game id"), err.help.as_deref());
+    }
+
+    #[test]
+    fn test_parse_alternatives() {
+        let err = parse_error(parse("game", "Game 1: green 7", game));
+        assert_eq!(8, err.span.offset());
+        assert_eq!(r#""red" or "blue""#, err.expected);
+        assert_eq!(Some("while parsing cubes"), err.help.as_deref());
+    }
+
+    #[test]
+    fn test_parse_trailing_input() {
+        let err = parse_error(parse("game", "Game 1: red 7!", game));
+        assert_eq!(13, err.span.offset());
+        assert_eq!("eof", err.expected);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "Game 1: red 7\nGame 2: blue 3\n";
+        assert_eq!(
+            vec![(1, 7), (2, 3)],
+            parse_lines("game", input, game).unwrap()
+        );
+
+        let input = "Game 1: red 7\nGame 2: blue x\n";
+        let err = parse_error(parse_lines("game", input, game));
+        assert_eq!(27, err.span.offset());
+        assert_eq!(input, &*err.src);
+    }
+
+    #[test]
+    fn test_parse_lines_reports_every_error() {
+        let input = "Game 1: red 7\nGame x: red 1\nGame 3: blue 3\nGame 4: green 2\n";
+
+        let partial = parse_lines_partial("game", input, game);
+        assert_eq!(vec![(1, 7), (3, 3)], partial.parsed);
+
+        let Err(Error::ParseLines(errors)) = partial.into_result() else {
+            panic!("expected every malformed line to be reported");
+        };
+        assert_eq!(4, errors.lines);
+        assert_eq!("cannot parse 2 of 4 game lines", errors.to_string());
+
+        let offsets = errors
+            .errors
+            .iter()
+            .map(|err| err.span.offset())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![19, 51], offsets);
+    }
+}
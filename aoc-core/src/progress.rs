@@ -0,0 +1,108 @@
+//! Opt-in progress bars for solutions that grind through a lot of work,
+//! e.g. brute-force scans kept around to check a smarter solution against.
+//!
+//! Solutions report work units through a [`Progress`] handle whether or not
+//! anyone is watching. Nothing is drawn until a binary calls [`enable`], e.g.
+//! through `--progress`, and never when stderr is not a terminal, so tests
+//! and benchmarks pay nothing for it.
+
+use std::{
+    io::IsTerminal,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+const TEMPLATE: &str =
+    "{msg} {wide_bar} {human_pos}/{human_len} [{elapsed_precise}] {per_sec} eta {eta}";
+
+/// Draws progress bars from now on, if stderr is a terminal; returns
+/// whether it does.
+pub fn enable() -> bool {
+    enable_on(std::io::stderr().is_terminal())
+}
+
+fn enable_on(terminal: bool) -> bool {
+    ENABLED.store(terminal, Ordering::Relaxed);
+    terminal
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Shared counter of work units, cheap to clone into rayon closures.
+///
+/// Every thread adds to the same bar, so rate and ETA are for all of them
+/// together. Updating takes a look at the clock, so add up batches of work
+/// rather than single items.
+#[derive(Debug, Clone)]
+pub struct Progress(Option<ProgressBar>);
+
+impl Progress {
+    /// Starts counting `len` units of work, e.g. `Progress::new(n, "seeds")`.
+    pub fn new(len: u64, unit: &str) -> Self {
+        if !is_enabled() {
+            return Self::hidden();
+        }
+
+        let style = ProgressStyle::with_template(TEMPLATE).expect("valid progress template");
+        let bar = ProgressBar::new(len)
+            .with_style(style)
+            .with_message(unit.to_string());
+        bar.enable_steady_tick(Duration::from_millis(200));
+        Self(Some(bar))
+    }
+
+    /// Handle that counts nothing.
+    pub fn hidden() -> Self {
+        Self(None)
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub fn inc(&self, units: u64) {
+        if let Some(bar) = &self.0 {
+            bar.inc(units);
+        }
+    }
+
+    /// Stops the bar, leaving its final state on screen.
+    pub fn finish(&self) {
+        if let Some(bar) = &self.0 {
+            bar.finish();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // one test, as it flips the global switch other tests would see
+    #[test]
+    fn test_hidden_until_enabled() {
+        let progress = Progress::new(10, "units");
+
+        assert!(!is_enabled());
+        assert!(progress.is_hidden());
+        progress.inc(5);
+        progress.finish();
+
+        assert!(!enable_on(false));
+        assert!(Progress::new(10, "units").is_hidden());
+
+        assert!(enable_on(true));
+        let progress = Progress::new(10, "units");
+        assert!(!progress.is_hidden());
+        progress.inc(10);
+        progress.finish();
+
+        enable_on(false);
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use miette::Context;
+
+use crate::{input::Source, progress, Answer, Part, Puzzle, Solution};
+
+/// Answer of one part together with the time it took to produce it.
+#[derive(Debug)]
+pub struct Run {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Parses `input` and solves `part`, measuring both steps together.
+pub fn time(puzzle: &dyn Puzzle, part: Part, input: &str) -> miette::Result<Run> {
+    let start = Instant::now();
+    let answer = puzzle
+        .answer(part, input)
+        .wrap_err_with(|| format!("process day {:02} {part}", puzzle.day()))?;
+
+    Ok(Run {
+        day: puzzle.day(),
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Command line of the `part1` / `part2` binaries, also for other binaries
+/// of a day that read its input, e.g. `runner::Args::parse()`.
+#[derive(Parser, Debug)]
+#[command(about = None)]
+pub struct Args {
+    /// Puzzle input file, `-` for stdin
+    /// [default: $AOC_INPUT, then day-XX/input.txt in the workspace]
+    #[arg(short, long)]
+    pub input: Option<Source>,
+
+    /// Draw progress bars of long-running solutions on stderr
+    #[arg(long)]
+    pub progress: bool,
+}
+
+impl Args {
+    /// Enables progress bars if asked to, then reads `--input`, or the
+    /// input of `part` by default.
+    pub fn read_input<S: Solution>(&self, part: Part) -> miette::Result<String> {
+        if self.progress {
+            progress::enable();
+        }
+        let input = match &self.input {
+            Some(source) => S::read_input(source),
+            None => S::input(part),
+        };
+
+        input
+            .map_err(miette::Report::new)
+            .wrap_err("read puzzle input")
+    }
+}
+
+/// Body of the `part1` / `part2` binaries every day crate ships.
+pub fn run<S: Solution>(part: Part) -> miette::Result<()> {
+    let input = Args::parse().read_input::<S>(part)?;
+
+    let result = S::solve(part, &input)
+        .map_err(miette::Report::new)
+        .wrap_err_with(|| format!("process {part}"))?;
+    println!("{}", result);
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use miette::Diagnostic;
+use serde::{Deserialize, Serialize};
+
+use crate::{input::Source, Answer, Error};
+
+/// One of the two halves of a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum Part {
+    One = 1,
+    Two = 2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{}", *self as u8)
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        part as u8
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        n.to_string().parse()
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    /// Accepts `1`, `2`, `part1` and `part2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("part").unwrap_or(s) {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("unknown part `{s}`, expected 1 or 2")),
+        }
+    }
+}
+
+/// A solved (or partially solved) day.
+///
+/// Every day crate implements this for a unit struct named after the day,
+/// e.g. `day_05::Day05`, so tooling can run any day without knowing how
+/// its input is parsed or what its answers look like.
+pub trait Solution {
+    /// Day of the month, `1..=25`.
+    const DAY: u8;
+
+    /// Puzzle input after the parse step shared by both parts.
+    ///
+    /// Days whose parts read the input differently use `&'a str` here and
+    /// parse inside `part1` / `part2`.
+    type Input<'a>;
+
+    type Error: Diagnostic + From<Error> + Send + Sync + 'static;
+
+    /// Reads the raw puzzle input, reporting failures as `Self::Error`.
+    fn read_input(source: &Source) -> Result<String, Self::Error> {
+        source.read().map_err(|err| Error::Io(err).into())
+    }
+
+    /// Reads the default input of `part`, see [`Source::lookup`].
+    ///
+    /// Binaries, benches and `aoc` all go through this, so a day whose parts
+    /// need different inputs can drop an `inputN.txt` next to `input.txt` or
+    /// override this method.
+    fn input(part: Part) -> Result<String, Self::Error> {
+        Self::read_input(&Source::lookup(Self::DAY, part))
+    }
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error>;
+
+    /// Parses the raw input and runs the given part on it.
+    fn solve(part: Part, input: &str) -> Result<Answer, Self::Error> {
+        let input = Self::parse(input)?;
+        match part {
+            Part::One => Self::part1(&input),
+            Part::Two => Self::part2(&input),
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days can be listed side by side.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    /// Same as [`Solution::input`], with the error turned into a report.
+    fn input(&self, part: Part) -> miette::Result<String>;
+
+    /// Same as [`Solution::solve`], with the error turned into a report.
+    fn answer(&self, part: Part, input: &str) -> miette::Result<Answer>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self, part: Part) -> miette::Result<String> {
+        S::input(part).map_err(miette::Report::new)
+    }
+
+    fn answer(&self, part: Part, input: &str) -> miette::Result<Answer> {
+        S::solve(part, input).map_err(miette::Report::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(thiserror::Error, Diagnostic, Debug)]
+    enum LengthsError {
+        #[error(transparent)]
+        Core(#[from] Error),
+
+        #[error("empty input")]
+        EmptyInput,
+    }
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        const DAY: u8 = 0;
+
+        type Input<'a> = Vec<&'a str>;
+        type Error = LengthsError;
+
+        fn input(part: Part) -> Result<String, Self::Error> {
+            Ok("a\nbb\nccc".repeat(part as usize))
+        }
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            if input.is_empty() {
+                return Err(LengthsError::EmptyInput);
+            }
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok(input.len().into())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok(input.iter().map(|line| line.len()).sum::<usize>().into())
+        }
+    }
+
+    #[test]
+    fn test_solve() -> miette::Result<()> {
+        assert_eq!(Answer::from(3u8), Lengths::solve(Part::One, "a\nbb\nccc")?);
+        assert_eq!(Answer::from(6u8), Lengths::solve(Part::Two, "a\nbb\nccc")?);
+        assert!(Lengths::solve(Part::One, "").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_input() {
+        let source = Source::File("does/not/exist.txt".into());
+        assert!(matches!(
+            Lengths::read_input(&source),
+            Err(LengthsError::Core(Error::Io(_)))
+        ));
+    }
+
+    #[test]
+    fn test_puzzle() -> miette::Result<()> {
+        let puzzle: &dyn Puzzle = &Lengths;
+        assert_eq!(0, puzzle.day());
+        assert_eq!("a\nbb\nccc", puzzle.input(Part::One)?);
+        assert_eq!(Answer::from(6u8), puzzle.answer(Part::Two, "a\nbb\nccc")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_display() {
+        assert_eq!("part1", Part::One.to_string());
+        assert_eq!("part2", Part::Two.to_string());
+    }
+
+    #[test]
+    fn test_part_from_str() {
+        assert_eq!(Ok(Part::One), "1".parse());
+        assert_eq!(Ok(Part::Two), "part2".parse());
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,31 @@
+//! Registers every `day-NN` dependency of this crate in `DAYS`, so a new day
+//! only needs to be added to `Cargo.toml`, which `just create` does.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let manifest = fs::read_to_string("Cargo.toml").expect("aoc/Cargo.toml is readable");
+    let mut days = manifest
+        .lines()
+        .filter_map(|line| line.split_once('=').map(|(name, _)| name.trim()))
+        .filter(|name| {
+            name.strip_prefix("day-")
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .collect::<Vec<_>>();
+    days.sort_unstable();
+
+    let entries = days
+        .iter()
+        .map(|day| {
+            let krate = day.replace('-', "_");
+            let solution = format!("Day{}", &day["day-".len()..]);
+            format!("    &{krate}::{solution},\n")
+        })
+        .collect::<String>();
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out, format!("&[\n{entries}]\n")).expect("OUT_DIR is writable");
+}
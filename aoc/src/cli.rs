@@ -0,0 +1,136 @@
+use std::{
+    fmt::{self, Display},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use aoc_core::{
+    input::{Source, YEAR},
+    Part,
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+#[command(about = "Runs Advent of Code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Solve one day, a range of days or all of them
+    Run {
+        /// Day (`5`) or inclusive range of days (`1-5`), all days if omitted
+        #[arg(short, long)]
+        day: Option<Days>,
+
+        /// Part (`1` or `2`), both parts if omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        /// Puzzle input file, `-` for stdin; needs a single `--day`
+        /// [default: day-XX/input.txt in the workspace]
+        #[arg(short, long)]
+        input: Option<Source>,
+    },
+
+    /// Check solved parts against the known answers in `answers.json`
+    Verify {
+        /// Day (`5`) or inclusive range of days (`1-5`), all days if omitted
+        #[arg(short, long)]
+        day: Option<Days>,
+
+        /// Part (`1` or `2`), both parts if omitted
+        #[arg(short, long)]
+        part: Option<Part>,
+
+        /// Record the answers of solved parts that have no known answer yet
+        #[arg(long)]
+        record: bool,
+    },
+
+    /// Extract example fixtures from the puzzle pages cached by `just get-input`
+    Examples {
+        /// Day (`5`) or inclusive range of days (`1-5`), every cached day if omitted
+        #[arg(short, long)]
+        day: Option<Days>,
+
+        /// Year of the event
+        #[arg(short, long, default_value_t = YEAR)]
+        year: u16,
+    },
+}
+
+/// Inclusive range of days picked on the command line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Days(RangeInclusive<u8>);
+
+impl Days {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+
+    pub fn is_single(&self) -> bool {
+        self.0.start() == self.0.end()
+    }
+
+    pub fn iter(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+}
+
+impl Display for Days {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_single() {
+            write!(f, "{}", self.0.start())
+        } else {
+            write!(f, "{}-{}", self.0.start(), self.0.end())
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let day = |s: &str| {
+            s.trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("`{s}` is not a day between 1 and 25"))
+        };
+
+        let days = match s.split_once('-') {
+            Some((from, to)) => day(from)?..=day(to)?,
+            None => day(s)?..=day(s)?,
+        };
+
+        if days.is_empty() {
+            return Err(format!("`{s}` is an empty range of days"));
+        }
+
+        Ok(Self(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(Ok(Days(5..=5)), "5".parse());
+        assert_eq!(Ok(Days(1..=7)), "1-7".parse());
+        assert!("7-1".parse::<Days>().is_err());
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+    }
+
+    #[test]
+    fn test_display_days() {
+        assert_eq!("5", Days(5..=5).to_string());
+        assert_eq!("1-7", Days(1..=7).to_string());
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_core::Puzzle;
+
+/// Every day crate in the workspace, in order.
+///
+/// Generated by `build.rs` from the `day-NN` dependencies in
+/// `aoc/Cargo.toml`, which `just create` adds for each new day.
+pub static DAYS: &[&dyn Puzzle] = include!(concat!(env!("OUT_DIR"), "/days.rs"));
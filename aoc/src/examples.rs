@@ -0,0 +1,49 @@
+use std::{fs, io};
+
+use aoc_core::{
+    examples::{self, examples_dir},
+    input::{cache_dir, workspace_dir},
+};
+use miette::{miette, Context, IntoDiagnostic};
+
+use crate::cli::Days;
+
+/// Cached puzzle page, relative to the cache directory of a day.
+const PAGE_FILE: &str = "puzzle.html";
+
+/// Turns the cached puzzle pages of `days` into example fixtures.
+///
+/// Days without a cached page are skipped, unless a single day was asked for.
+pub fn extract(days: Option<Days>, year: u16) -> miette::Result<()> {
+    let single = days.as_ref().is_some_and(Days::is_single);
+    let days = days.map_or(1..=25, |days| days.iter());
+
+    for day in days {
+        let page = cache_dir(year, day).join(PAGE_FILE);
+        let html = match fs::read_to_string(&page) {
+            Ok(html) => html,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !single => continue,
+            Err(err) => {
+                return Err(err)
+                    .into_diagnostic()
+                    .wrap_err_with(|| format!("cannot read {}", page.display()))
+            }
+        };
+
+        let found = examples::extract(&html);
+        if found.iter().all(|example| example.input.is_none()) {
+            return Err(miette!("no example in {}", page.display()));
+        }
+
+        let written = examples::save(&examples_dir(day), &found)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("cannot save examples of day {day:02}"))?;
+
+        for path in written {
+            let path = path.strip_prefix(workspace_dir()).unwrap_or(&path);
+            println!("day {day:02}  wrote {}", path.display());
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use aoc_core::{input::Source, runner, Answer, Part, Puzzle};
+use clap::Parser;
+use miette::{miette, Context, IntoDiagnostic};
+
+use crate::{
+    cli::{Cli, Command, Days},
+    days::DAYS,
+};
+
+mod cli;
+mod days;
+mod examples;
+mod verify;
+
+/// Day crates picked by `--day`, all of them if omitted; picking none is an
+/// error, so a typo in `--day` does not pass for success.
+fn select(days: Option<Days>) -> miette::Result<Vec<&'static dyn Puzzle>> {
+    let puzzles = DAYS
+        .iter()
+        .copied()
+        .filter(|puzzle| days.as_ref().is_none_or(|days| days.contains(puzzle.day())))
+        .collect::<Vec<_>>();
+
+    match days {
+        Some(days) if puzzles.is_empty() => {
+            let available = DAYS
+                .iter()
+                .map(|puzzle| puzzle.day().to_string())
+                .collect::<Vec<_>>();
+            Err(miette!(
+                "no day crate for --day {days}, there are days {}",
+                available.join(", ")
+            ))
+        }
+        _ => Ok(puzzles),
+    }
+}
+
+fn parts(part: Option<Part>) -> Vec<Part> {
+    part.map_or(Part::ALL.to_vec(), |part| vec![part])
+}
+
+/// Prints `head` and `tail` around the right-aligned `answer`, or the lines
+/// of a multi-line answer underneath `head`.
+fn print_answer(head: &str, answer: &Answer, tail: &str) {
+    if answer.is_multiline() {
+        println!("{}", format!("{head}  {tail}").trim_end());
+        for line in answer.to_string().lines() {
+            println!("    {line}");
+        }
+    } else {
+        println!("{}", format!("{head}  {answer:>16}  {tail}").trim_end());
+    }
+}
+
+fn run(days: Option<Days>, part: Option<Part>, input: Option<Source>) -> miette::Result<()> {
+    if input.is_some() && !days.as_ref().is_some_and(Days::is_single) {
+        return Err(miette!("`--input` needs a single `--day`"));
+    }
+
+    // read once: stdin cannot be read again for the second part
+    let input = input
+        .map(|source| source.read().into_diagnostic())
+        .transpose()
+        .wrap_err("read puzzle input")?;
+    let mut total = Duration::ZERO;
+
+    for puzzle in select(days)? {
+        for part in parts(part) {
+            let default;
+            let input = match &input {
+                Some(input) => input,
+                None => {
+                    default = puzzle.input(part).wrap_err("read puzzle input")?;
+                    &default
+                }
+            };
+
+            let run = runner::time(puzzle, part, input)?;
+            let head = format!("day {:02} {}", run.day, run.part);
+            print_answer(&head, &run.answer, &format!("({:.2?})", run.elapsed));
+            total += run.elapsed;
+        }
+    }
+
+    println!("total {total:.2?}");
+    Ok(())
+}
+
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    match Cli::parse().command {
+        Command::Run { day, part, input } => run(day, part, input),
+        Command::Verify { day, part, record } => verify::verify(select(day)?, &parts(part), record),
+        Command::Examples { day, year } => examples::extract(day, year),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() -> miette::Result<()> {
+        assert_eq!(DAYS.len(), select(None)?.len());
+        assert_eq!(
+            vec![5],
+            select("5".parse().ok())?
+                .iter()
+                .map(|p| p.day())
+                .collect::<Vec<_>>()
+        );
+
+        let Err(err) = select("25".parse().ok()) else {
+            panic!("there is no day 25 yet");
+        };
+        let err = err.to_string();
+        assert!(
+            err.starts_with("no day crate for --day 25, there are days 1, 2"),
+            "{err}"
+        );
+
+        Ok(())
+    }
+}
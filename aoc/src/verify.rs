@@ -0,0 +1,203 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+};
+
+use aoc_core::{
+    answers::{fingerprint, Answers},
+    runner::{self, Run},
+    Answer, Part, Puzzle,
+};
+use miette::{miette, Context, IntoDiagnostic};
+
+use crate::print_answer;
+
+/// How a part's answer compares to the known answers.
+#[derive(Debug)]
+pub enum Verdict {
+    Correct,
+    Mismatch {
+        expected: Answer,
+    },
+    /// Solved, but nobody has recorded the answer for this input yet.
+    Missing,
+    /// The part reports [`aoc_core::Error::Unsolved`] or still ends in `todo!()`.
+    Unsolved,
+    Failed(miette::Report),
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "ok"),
+            Self::Mismatch { .. } => write!(f, "MISMATCH"),
+            Self::Missing => write!(f, "missing"),
+            Self::Unsolved => write!(f, "unsolved"),
+            Self::Failed(_) => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// Runs `part`, turning a `todo!()` panic into [`Verdict::Unsolved`].
+fn catch_unsolved(puzzle: &dyn Puzzle, part: Part, input: &str) -> Result<Run, Verdict> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| runner::time(puzzle, part, input)));
+    panic::set_hook(hook);
+
+    match result {
+        Ok(Ok(run)) => Ok(run),
+        Ok(Err(report)) if is_unsolved(&report) => Err(Verdict::Unsolved),
+        Ok(Err(report)) => Err(Verdict::Failed(report)),
+        Err(payload) => match panic_message(&*payload) {
+            Some(message) if message.starts_with("not yet implemented") => Err(Verdict::Unsolved),
+            Some(message) => Err(Verdict::Failed(miette!("panicked: {message}"))),
+            None => Err(Verdict::Failed(miette!("panicked"))),
+        },
+    }
+}
+
+fn is_unsolved(report: &miette::Report) -> bool {
+    report
+        .code()
+        .is_some_and(|code| code.to_string() == "aoc::unsolved")
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+}
+
+/// Checks every selected part against `answers.json`.
+///
+/// With `record`, answers of solved parts that have no known answer yet are
+/// written back to the file.
+pub fn verify(
+    puzzles: impl IntoIterator<Item = &'static dyn Puzzle>,
+    parts: &[Part],
+    record: bool,
+) -> miette::Result<()> {
+    let path = Answers::path();
+    let mut answers = Answers::load(&path)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("load {}", path.display()))?;
+
+    let mut recorded = 0;
+    let mut failures = 0;
+
+    for puzzle in puzzles {
+        for &part in parts {
+            let input = puzzle.input(part).wrap_err("read puzzle input")?;
+            let key = fingerprint(&input);
+
+            let (answer, verdict) = match catch_unsolved(puzzle, part, &input) {
+                Ok(run) => {
+                    let verdict = match answers.get(puzzle.day(), part, &key) {
+                        Some(expected) if *expected == run.answer => Verdict::Correct,
+                        Some(expected) => Verdict::Mismatch {
+                            expected: expected.clone(),
+                        },
+                        None => Verdict::Missing,
+                    };
+                    (Some(run.answer), verdict)
+                }
+                Err(verdict) => (None, verdict),
+            };
+
+            let head = format!("day {:02} {}  {:<8}", puzzle.day(), part, verdict);
+            match &answer {
+                Some(answer) => print_answer(&head, answer, ""),
+                None => println!("{head}"),
+            }
+
+            match verdict {
+                Verdict::Mismatch { expected } => {
+                    print_answer("    expected", &expected, "");
+                    failures += 1;
+                }
+                Verdict::Failed(report) => {
+                    println!("{report:?}");
+                    failures += 1;
+                }
+                Verdict::Missing if record => {
+                    let answer = answer.expect("missing answers come from solved parts");
+                    answers.insert(puzzle.day(), part, &key, answer);
+                    recorded += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if recorded > 0 {
+        answers
+            .save(&path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("save {}", path.display()))?;
+        println!("recorded {recorded} new answer(s) in {}", path.display());
+    }
+
+    if failures > 0 {
+        return Err(miette!("{failures} part(s) failed verification"));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Stub;
+
+    impl Puzzle for Stub {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn input(&self, _part: Part) -> miette::Result<String> {
+            Ok(String::new())
+        }
+
+        fn answer(&self, part: Part, _input: &str) -> miette::Result<Answer> {
+            match part {
+                Part::One => Ok(42u8.into()),
+                Part::Two => todo!("day 00 - part 2"),
+            }
+        }
+    }
+
+    struct Unsolved;
+
+    impl Puzzle for Unsolved {
+        fn day(&self) -> u8 {
+            0
+        }
+
+        fn input(&self, _part: Part) -> miette::Result<String> {
+            Ok(String::new())
+        }
+
+        fn answer(&self, part: Part, _input: &str) -> miette::Result<Answer> {
+            Err(aoc_core::Error::Unsolved { day: 0, part }.into())
+        }
+    }
+
+    #[test]
+    fn test_catch_unsolved() {
+        assert!(
+            matches!(catch_unsolved(&Stub, Part::One, ""), Ok(run) if run.answer == 42u8.into())
+        );
+        assert!(matches!(
+            catch_unsolved(&Stub, Part::Two, ""),
+            Err(Verdict::Unsolved)
+        ));
+        assert!(matches!(
+            catch_unsolved(&Unsolved, Part::One, ""),
+            Err(Verdict::Unsolved)
+        ));
+    }
+}
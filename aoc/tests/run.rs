@@ -0,0 +1,38 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+#[test]
+fn test_run_reads_stdin_once() {
+    let input = include_str!("../../day-06/examples/input.txt");
+
+    let mut aoc = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "6", "--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    aoc.stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = aoc.wait_with_output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        stdout.contains("day 06 part1") && stdout.contains(" 288 "),
+        "{stdout}"
+    );
+    assert!(
+        stdout.contains("day 06 part2") && stdout.contains(" 71503 "),
+        "{stdout}"
+    );
+}
@@ -1,13 +1,14 @@
+use aoc_core::{Part, Solution};
 use criterion::{
     criterion_group, criterion_main, Criterion,
 };
 use {{crate_name}}::*;
 
 fn criterion_benchmark_part1(c: &mut Criterion) {
-    let input = include_str!("../input1.txt");
+    let input = {{project-name | upper_camel_case}}::input(Part::One).unwrap();
 
     let mut group = c.benchmark_group("{{crate_name}}::part1");
-    group.bench_with_input("part1", input, |b, input| {
+    group.bench_with_input("part1", input.as_str(), |b, input| {
         b.iter(|| part1::process(input))
     });
 
@@ -15,10 +16,10 @@ fn criterion_benchmark_part1(c: &mut Criterion) {
 }
 
 fn criterion_benchmark_part2(c: &mut Criterion) {
-    let input = include_str!("../input2.txt");
+    let input = {{project-name | upper_camel_case}}::input(Part::Two).unwrap();
 
     let mut group = c.benchmark_group("{{crate_name}}::part2");
-    group.bench_with_input("part2", input, |b, input| {
+    group.bench_with_input("part2", input.as_str(), |b, input| {
         b.iter(|| part2::process(input))
     });
 
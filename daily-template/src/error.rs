@@ -4,6 +4,6 @@ use thiserror::Error;
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
+    #[diagnostic(transparent)]
+    Core(#[from] aoc_core::Error),
 }
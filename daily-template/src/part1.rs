@@ -1,19 +1,28 @@
-use crate::error::AocError;
+use aoc_core::{Answer, Error::Unsolved, Part, Solution};
+
+use crate::{error::AocError, {{project-name | upper_camel_case}}};
 
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    todo!("day 01 - part 1");
+pub fn process(_input: &str) -> miette::Result<Answer, AocError> {
+    Err(Unsolved {
+        day: {{project-name | upper_camel_case}}::DAY,
+        part: Part::One,
+    }
+    .into())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::examples::Example;
+
     use super::*;
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let example = Example::load({{project-name | upper_camel_case}}::DAY, Part::One)?;
+
+        assert_eq!(example.answer, Some(process(&example.input)?));
+
         Ok(())
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Part, Solution};
 use day_01::*;
 
 fn main() {
@@ -5,18 +6,19 @@ fn main() {
     divan::main();
 }
 
+/// Reads the input up front, set `AOC_INPUT` to bench against another file.
+fn input(part: Part) -> String {
+    Day01::input(part).unwrap()
+}
+
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = input(Part::One);
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = input(Part::Two);
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
@@ -4,14 +4,14 @@ use thiserror::Error;
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
+    #[diagnostic(transparent)]
+    Core(#[from] aoc_core::Error),
 
     #[error("no calibration value in `{0}`")]
-    #[diagnostic(code(aoc::no_calibration_value))]
+    #[diagnostic(code(day_01::no_calibration_value))]
     NoCalibrationValue(String),
 
     #[error("cannot parse `{0} as number")]
-    #[diagnostic(code(aoc::cannot_parse_as_number))]
+    #[diagnostic(code(day_01::cannot_parse_as_number))]
     CannotParseAsNumber(String),
 }
@@ -1,10 +1,12 @@
+use aoc_core::Answer;
+
 use crate::error::AocError;
 
 #[tracing::instrument]
 fn recover_calibration_value(line: &str) -> Result<u32, AocError> {
     let mut digits = line.chars().filter_map(|c| c.to_digit(10));
     let first = digits.next();
-    let last = digits.last().or(first);
+    let last = digits.next_back().or(first);
     let (Some(x), Some(y)) = (first, last) else {
         return Err(AocError::NoCalibrationValue(line.to_string()));
     };
@@ -14,30 +16,27 @@ fn recover_calibration_value(line: &str) -> Result<u32, AocError> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
     input
         .lines()
         .map(recover_calibration_value)
         .sum::<Result<u32, _>>()
-        .map(|r| r.to_string())
+        .map(Answer::from)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+
     use super::*;
+    use crate::Day01;
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        // In this example, the calibration values of these four lines are:
-        // 12, 38, 15, and 77
-        // Adding these together produces 142
+        let example = Example::load(Day01::DAY, Part::One)?;
 
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
+        assert_eq!(example.answer, Some(process(&example.input)?));
 
-        assert_eq!("142", process(input)?);
         Ok(())
     }
 }
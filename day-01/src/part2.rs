@@ -1,3 +1,4 @@
+use aoc_core::Answer;
 use itertools::unfold;
 
 use crate::error::AocError;
@@ -51,30 +52,41 @@ fn recover_calibration_value(line: &str) -> Result<u32, AocError> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
     input
         .lines()
         .map(recover_calibration_value)
         .sum::<Result<u32, _>>()
-        .map(|r| r.to_string())
+        .map(Answer::from)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
     use rstest::rstest;
 
     use super::*;
+    use crate::Day01;
 
     #[rstest]
-    #[case("two1nine", "29")]
-    #[case("eightwothree", "83")]
-    #[case("abcone2threexyz", "13")]
-    #[case("xtwone3four", "24")]
-    #[case("4nineeightseven2", "42")]
-    #[case("zoneight234", "14")]
-    #[case("7pqrstsixteen", "76")]
-    fn test_process(#[case] input: &str, #[case] expected: &str) -> miette::Result<()> {
-        assert_eq!(expected, process(input)?);
+    #[case("two1nine", 29)]
+    #[case("eightwothree", 83)]
+    #[case("abcone2threexyz", 13)]
+    #[case("xtwone3four", 24)]
+    #[case("4nineeightseven2", 42)]
+    #[case("zoneight234", 14)]
+    #[case("7pqrstsixteen", 76)]
+    fn test_process(#[case] input: &str, #[case] expected: u32) -> miette::Result<()> {
+        assert_eq!(Answer::from(expected), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_example() -> miette::Result<()> {
+        let example = Example::load(Day01::DAY, Part::Two)?;
+
+        assert_eq!(example.answer, Some(process(&example.input)?));
+
         Ok(())
     }
 }
@@ -4,14 +4,10 @@ use thiserror::Error;
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
+    #[diagnostic(transparent)]
+    Core(#[from] aoc_core::Error),
 
     #[error("cannot parse `{0}` as number")]
-    #[diagnostic(code(aoc::cannot_parse_as_number))]
+    #[diagnostic(code(day_02::cannot_parse_as_number))]
     CannotParseAsNumber(String),
-
-    #[error("cannot parse `{0}` as Game")]
-    #[diagnostic(code(aoc::parser_game_error))]
-    ParseGameError(String),
 }
@@ -1,3 +1,5 @@
+use aoc_core::Answer;
+
 use crate::error::AocError;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -41,66 +43,83 @@ impl Game {
 
 mod parser {
     use super::*;
+    use aoc_core::parsing::{parse_lines_partial, IResult, Partial, Span};
     use nom::{
         branch::alt,
-        bytes::complete::tag,
         character::complete::{space0, u32},
-        combinator::{map_res, value},
+        combinator::{cut, map, value},
         multi::separated_list1,
         sequence::{delimited, preceded, tuple},
-        IResult,
     };
+    use nom_supreme::{tag::complete::tag, ParserExt};
 
-    fn game(input: &str) -> IResult<&str, Game, ()> {
+    fn game(input: Span) -> IResult<Game> {
         // Game 13: 7 blue, 8 red; 5 green, 15 blue, 2 red; 7 green, 3 blue, 12 red
 
         let color = alt((
-            value(Color::Red, tag::<&str, &str, ()>("red")),
-            value(Color::Green, tag::<&str, &str, ()>("green")),
-            value(Color::Blue, tag::<&str, &str, ()>("blue")),
-        ));
-
-        let colored_cubes = tuple((u32, preceded(space0, color)));
-        let colored_cubes_list = separated_list1(tag(", "), colored_cubes);
-        let game_round = separated_list1(tag("; "), colored_cubes_list);
-        let game_id = delimited(tag("Game "), u32, tag(": "));
+            value(Color::Red, tag("red")),
+            value(Color::Green, tag("green")),
+            value(Color::Blue, tag("blue")),
+        ))
+        .context("color");
+
+        let colored_cubes = tuple((u32, preceded(space0, color))).context("cubes");
+        let colored_cubes_list = separated_list1(tag(", "), cut(colored_cubes));
+        let game_round = separated_list1(tag("; "), colored_cubes_list).context("rounds");
+        let game_id = delimited(tag("Game "), u32, tag(": ")).context("game id");
         let game = tuple((game_id, game_round));
 
-        map_res(game, |(id, rounds)| Ok::<Game, ()>(Game::new(id, rounds)))(input)
+        map(game, |(id, rounds)| Game::new(id, rounds))(input)
+    }
+
+    pub fn parse_games(input: &str) -> Result<Vec<Game>, AocError> {
+        Ok(parse_games_partial(input).into_result()?)
     }
 
-    pub fn parse_game(input: &str) -> Result<Game, AocError> {
-        game(input)
-            .map(|x| x.1)
-            .map_err(|_| AocError::ParseGameError(input.to_string()))
+    /// Every game that parses, next to the errors of those that don't.
+    pub fn parse_games_partial(input: &str) -> Partial<Game> {
+        parse_lines_partial("game", input, game)
     }
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
-    let games = input
-        .lines()
-        .map(parser::parse_game)
-        .collect::<Result<Vec<Game>, _>>()?;
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
+    let games = parser::parse_games(input)?;
 
     let sum = games.iter().map(Game::power).sum::<u32>();
 
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+
     use super::*;
+    use crate::Day02;
+
+    #[test]
+    fn test_parse_games_partial() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 grean\nGame 3: 8 green";
+        let partial = parser::parse_games_partial(input);
+
+        let ids = partial
+            .parsed
+            .iter()
+            .map(|game| game.id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 3], ids);
+
+        let errors = partial.errors.expect("game 2 should be reported").errors;
+        assert_eq!(1, errors.len());
+        assert_eq!(input.find("grean"), Some(errors[0].span.offset()));
+    }
 
     #[test]
     fn test_process() -> miette::Result<()> {
-        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let example = Example::load(Day02::DAY, Part::Two)?;
 
-        assert_eq!("2286", process(input)?);
+        assert_eq!(example.answer, Some(process(&example.input)?));
 
         Ok(())
     }
@@ -1,5 +1,5 @@
-use day_03::part1::process;
-use miette::Context;
+use aoc_core::{runner, Part};
+use day_03::Day03;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -13,8 +13,5 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
-    println!("{}", result);
-    Ok(())
+    runner::run::<Day03>(Part::One)
 }
@@ -1,4 +1,29 @@
+use aoc_core::{Answer, Solution};
+
+use crate::error::AocError;
+
 pub mod error;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part2::process(input)
+    }
+}
@@ -1,3 +1,5 @@
+use aoc_core::Answer;
+
 use crate::error::AocError;
 
 fn is_symbol(char: char) -> bool {
@@ -5,7 +7,7 @@ fn is_symbol(char: char) -> bool {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
     let matrix = input
         .lines()
         .map(|line| format!("{line}.").chars().collect())
@@ -66,26 +68,22 @@ pub fn process(input: &str) -> miette::Result<String, AocError> {
         .map(|x| x.1)
         .sum::<u32>();
 
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+
     use super::*;
+    use crate::Day03;
 
     #[test]
     fn test_process0() -> miette::Result<()> {
-        let input = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-        assert_eq!("4361", process(input)?);
+        let example = Example::load(Day03::DAY, Part::One)?;
+
+        assert_eq!(example.answer, Some(process(&example.input)?));
+
         Ok(())
     }
 
@@ -103,7 +101,7 @@ mod tests {
 2.2......12.
 .*.........*
 1.1..503+.56";
-        assert_eq!("925", process(input)?);
+        assert_eq!(Answer::from(925u32), process(input)?);
         Ok(())
     }
 
@@ -121,7 +119,7 @@ mod tests {
 2.2......12.
 .*.........*
 1.1.......56";
-        assert_eq!("413", process(input)?);
+        assert_eq!(Answer::from(413u32), process(input)?);
         Ok(())
     }
 }
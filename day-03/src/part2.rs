@@ -1,3 +1,5 @@
+use aoc_core::Answer;
+
 use crate::error::AocError;
 use itertools::Itertools;
 
@@ -132,29 +134,25 @@ impl EngineSchematic {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
     let engine_schematic = EngineSchematic::new(input);
     let result = engine_schematic.calculate_gear_ratio_sum();
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+
     use super::*;
+    use crate::Day03;
 
     #[test]
     fn test_process0() -> miette::Result<()> {
-        let input = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-        assert_eq!("467835", process(input)?);
+        let example = Example::load(Day03::DAY, Part::Two)?;
+
+        assert_eq!(example.answer, Some(process(&example.input)?));
+
         Ok(())
     }
 }
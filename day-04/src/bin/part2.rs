@@ -1,5 +1,5 @@
-use day_04::part2::process;
-use miette::Context;
+use aoc_core::{runner, Part};
+use day_04::Day04;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -13,8 +13,5 @@ fn main() -> miette::Result<()> {
     #[cfg(not(feature = "dhat-heap"))]
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
-    println!("{}", result);
-    Ok(())
+    runner::run::<Day04>(Part::Two)
 }
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use aoc_core::Answer;
+
 use crate::error::AocError;
 
 #[derive(Debug, PartialEq)]
@@ -40,57 +42,63 @@ impl TryFrom<&str> for Card {
 mod parsing {
     use super::*;
 
+    use aoc_core::parsing::{parse, parse_lines_partial, IResult, Partial, Span};
     use nom::{
-        bytes::complete::tag,
         character::complete::{space1, u32},
-        combinator::map_res,
+        combinator::map,
         multi::separated_list1,
         sequence::{delimited, preceded, separated_pair, tuple},
-        IResult,
     };
+    use nom_supreme::{tag::complete::tag, ParserExt};
 
-    fn card(input: &str) -> IResult<&str, Card> {
+    fn card(input: Span) -> IResult<Card> {
         let card = tuple((
-            delimited(tuple((tag("Card"), space1)), u32, tag(":")),
+            delimited(tuple((tag("Card"), space1)), u32, tag(":")).context("card id"),
             preceded(
                 space1,
                 separated_pair(
-                    separated_list1(space1, u32),
+                    separated_list1(space1, u32).context("chosen numbers"),
                     delimited(space1, tag("|"), space1),
-                    separated_list1(space1, u32),
+                    separated_list1(space1, u32).context("winning numbers"),
                 ),
             ),
         ));
 
-        map_res(card, |(id, (chosen, winning))| {
-            let card = Card::new(id, &chosen, &winning);
-            Ok::<Card, ()>(card)
+        map(card, |(id, (chosen, winning))| {
+            Card::new(id, &chosen, &winning)
         })(input)
     }
 
     pub fn parse_card(input: &str) -> Result<Card, AocError> {
-        card(input)
-            .map(|x| x.1)
-            .map_err(|_| AocError::ParseCardError(input.to_string()))
+        Ok(parse("card", input, card)?)
+    }
+
+    pub fn parse_cards(input: &str) -> Result<Vec<Card>, AocError> {
+        Ok(parse_cards_partial(input).into_result()?)
+    }
+
+    /// Every card that parses, next to the errors of those that don't.
+    pub fn parse_cards_partial(input: &str) -> Partial<Card> {
+        parse_lines_partial("card", input, card)
     }
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<String, AocError> {
-    input
-        .lines()
-        .try_fold(0, |acc, line| {
-            let card = Card::try_from(line)?;
-            Ok(acc + card.points())
-        })
-        .map(|x| x.to_string())
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
+    let points = parsing::parse_cards(input)?
+        .iter()
+        .map(Card::points)
+        .sum::<u32>();
+
+    Ok(points.into())
 }
 
 #[cfg(test)]
 mod tests {
-    use miette::IntoDiagnostic;
+    use aoc_core::{examples::Example, Part, Solution};
 
     use super::*;
+    use crate::Day04;
 
     #[test]
     fn test_parse_card() -> miette::Result<()> {
@@ -104,15 +112,57 @@ mod tests {
     }
 
     #[test]
-    fn test_process() -> miette::Result<()> {
+    fn test_parse_reports_every_line() {
         let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 2: 13 32 20 16 61 / 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+Card x: 41 92 73 84 69 | 59 84 76 51 58  5 54 83";
+
+        let Err(AocError::Core(aoc_core::Error::ParseLines(errors))) = process(input) else {
+            panic!("both malformed cards should be reported");
+        };
+
+        let offsets = errors
+            .errors
+            .iter()
+            .map(|err| err.span.offset())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![input.find('/').unwrap(), input.find('x').unwrap()],
+            offsets
+        );
+    }
+
+    #[test]
+    fn test_parse_cards_partial() {
+        let input = "Card 1: 41 48 | 83 86\nCard x: 13 32 | 61 30\nCard 3: 1 21 | 69 82\nCard 4: 41 92 / 59 84";
+        let partial = parsing::parse_cards_partial(input);
+
+        let ids = partial
+            .parsed
+            .iter()
+            .map(|card| card.id)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 3], ids);
+
+        let offsets = partial
+            .errors
+            .expect("cards x and 4 should be reported")
+            .errors
+            .iter()
+            .map(|err| err.span.offset())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![input.find('x').unwrap(), input.find('/').unwrap()],
+            offsets
+        );
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let example = Example::load(Day04::DAY, Part::One)?;
 
-        assert_eq!("13", process(input)?);
+        assert_eq!(example.answer, Some(process(&example.input)?));
 
         Ok(())
     }
@@ -0,0 +1,1091 @@
+//! Almanac of day 5: seeds, and categories of lines mapping them from one
+//! kind of number to the next, down to locations.
+//!
+//! Part 1 reads the `seeds:` line as single seeds, part 2 as ranges of seeds;
+//! both end up as [`Almanac::seed_ranges`], so everything else is shared.
+
+use aoc_core::progress::Progress;
+use miette::SourceSpan;
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+
+pub use self::parsing::parse_almanac;
+use crate::{
+    error::{AlmanacIssue, AocError},
+    explain::{Step, Trace},
+    graph::CategoryGraph,
+    piecewise::PiecewiseMap,
+};
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+};
+
+/// Whether `start..start + len` has numbers past `u64::MAX`. The range may
+/// end at 2^64 as long as its last number fits.
+fn overflows(start: u64, len: u64) -> bool {
+    len.checked_sub(1)
+        .is_some_and(|last| start.checked_add(last).is_none())
+}
+
+#[derive(Debug, Clone)]
+pub struct AlmanacLine {
+    src: u64,
+    dst: u64,
+    len: u64,
+    /// Where the line is in the input, for diagnostics.
+    span: SourceSpan,
+}
+
+// seeds: 3416930225 56865175 4245248379 7142355 1808166864 294882110 863761171 233338109 4114335326 67911591 1198254212 504239157 3491380151 178996923 3965970270 15230597 2461206486 133606394 2313929258 84595688
+
+// dst        src        len
+// 3534435790 4123267198 50004089
+
+impl AlmanacLine {
+    pub fn new(dst: u64, src: u64, len: u64) -> Self {
+        Self {
+            src,
+            dst,
+            len,
+            span: (0, 0).into(),
+        }
+    }
+
+    pub fn with_span(mut self, span: impl Into<SourceSpan>) -> Self {
+        self.span = span.into();
+        self
+    }
+
+    /// Maps n ∈ src to m ∈ dst, returns n if n ∉ src.
+    ///
+    /// Works on offsets into the line, so nothing overflows as long as
+    /// [`AlmanacCategory::issues`] has no [`AlmanacIssue::Overflow`].
+    pub fn try_map(&self, n: u64) -> Option<u64> {
+        // m = 52 + (n - 50)
+        // n = 53 => m = 52 + 3 = 55
+        let offset = n
+            .checked_sub(self.src)
+            .filter(|&offset| offset < self.len)?;
+        Some(self.dst + offset)
+    }
+
+    /// Maps m ∈ dst back to n ∈ src, the inverse of [`Self::try_map`].
+    pub fn try_unmap(&self, m: u64) -> Option<u64> {
+        let offset = m
+            .checked_sub(self.dst)
+            .filter(|&offset| offset < self.len)?;
+        Some(self.src + offset)
+    }
+
+    /// Numbers of src this line maps.
+    pub fn src_range(&self) -> Range<u128> {
+        let src = u128::from(self.src);
+        src..src + u128::from(self.len)
+    }
+
+    /// Numbers of dst this line maps to.
+    pub fn dst_range(&self) -> Range<u128> {
+        let dst = u128::from(self.dst);
+        dst..dst + u128::from(self.len)
+    }
+
+    /// Maps a range that lies within [`Self::src_range`] as a whole.
+    fn map_range(&self, range: Range<u128>) -> Range<u128> {
+        let (src, dst) = (u128::from(self.src), u128::from(self.dst));
+        dst + (range.start - src)..dst + (range.end - src)
+    }
+
+    /// Numbers of src this line maps into `range`, if any.
+    pub fn unmap_range(&self, range: Range<u128>) -> Option<Range<u128>> {
+        let (src, dst) = (u128::from(self.src), u128::from(self.dst));
+        let start = range.start.max(dst);
+        let end = range.end.min(self.dst_range().end);
+
+        (start < end).then(|| src + (start - dst)..src + (end - dst))
+    }
+}
+
+/// Written like in the almanac, `dst src len`.
+impl Display for AlmanacLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.dst, self.src, self.len)
+    }
+}
+
+/// `src-to-dst map:` and its lines.
+#[derive(Debug)]
+pub struct AlmanacCategory {
+    pub src: String,
+    pub dst: String,
+    pub lines: Vec<AlmanacLine>,
+}
+
+impl AlmanacCategory {
+    pub fn new(src: &str, dst: &str, lines: Vec<AlmanacLine>) -> Self {
+        Self {
+            src: src.to_string(),
+            dst: dst.to_string(),
+            lines,
+        }
+    }
+
+    /// `src-to-dst`, as in the header of the category.
+    pub fn name(&self) -> String {
+        format!("{}-to-{}", self.src, self.dst)
+    }
+
+    /// Line mapping `n`, `None` if n passes through.
+    pub fn line_for(&self, n: u64) -> Option<&AlmanacLine> {
+        self.lines.iter().find(|line| line.try_map(n).is_some())
+    }
+
+    pub fn look_up(&self, n: u64) -> u64 {
+        self.line_for(n)
+            .and_then(|line| line.try_map(n))
+            .unwrap_or(n)
+    }
+
+    /// Lines of length 0, ranges past `u64::MAX`, and lines overlapping
+    /// another one, which [`Self::look_up`] would quietly resolve in favour
+    /// of whichever comes first.
+    pub fn issues(&self) -> Vec<AlmanacIssue> {
+        let category = self.name();
+        let mut issues = Vec::new();
+        let mut checked = Vec::with_capacity(self.lines.len());
+
+        for line in &self.lines {
+            if line.len == 0 {
+                issues.push(AlmanacIssue::EmptyLine {
+                    category: category.clone(),
+                    span: line.span,
+                });
+                continue;
+            }
+
+            let overflows = [("source", line.src), ("destination", line.dst)]
+                .into_iter()
+                .filter(|&(_, start)| overflows(start, line.len))
+                .map(|(what, start)| AlmanacIssue::Overflow {
+                    what,
+                    start,
+                    len: line.len,
+                    span: line.span,
+                })
+                .collect::<Vec<_>>();
+            if overflows.is_empty() {
+                checked.push(line);
+            }
+            issues.extend(overflows);
+        }
+
+        // sorted by start, a line overlaps an earlier one iff it starts before
+        // the furthest end so far
+        checked.sort_by_key(|line| line.src);
+        let mut furthest: Option<&AlmanacLine> = None;
+        for line in checked {
+            let end = line.src_range().end;
+            if let Some(other) = furthest {
+                let other_end = other.src_range().end;
+                let start = u128::from(line.src);
+                if start < other_end {
+                    issues.push(AlmanacIssue::Overlap {
+                        category: category.clone(),
+                        overlap: start..end.min(other_end),
+                        span: line.span,
+                        other: other.span,
+                    });
+                }
+                if end <= other_end {
+                    continue;
+                }
+            }
+            furthest = Some(line);
+        }
+
+        issues
+    }
+
+    /// The whole category as one map, gaps between lines included.
+    pub fn piecewise(&self) -> PiecewiseMap {
+        PiecewiseMap::new(self.lines.iter().map(|line| (line.src_range(), line.dst)))
+    }
+
+    /// Every n with `look_up(n) == m`: what lines map to m, and m itself
+    /// if no line maps it away.
+    pub fn look_up_rev(&self, m: u64) -> Vec<u64> {
+        let mut found = self
+            .lines
+            .iter()
+            .filter_map(|line| line.try_unmap(m))
+            .chain([m])
+            .filter(|&n| self.look_up(n) == m)
+            .collect::<Vec<_>>();
+        found.sort_unstable();
+        found.dedup();
+        found
+    }
+
+    /// Splits `range` wherever a line starts or ends, tagging each piece
+    /// with the index of the line mapping it, `None` for pass-through.
+    fn claims(&self, range: Range<u128>) -> Vec<(Option<usize>, Range<u128>)> {
+        let mut claimed = Vec::new();
+        let mut unclaimed = vec![range];
+
+        for (index, line) in self.lines.iter().enumerate() {
+            let src = line.src_range();
+            let mut rest = Vec::new();
+
+            for range in unclaimed {
+                let before = range.start..range.end.min(src.start);
+                let inside = range.start.max(src.start)..range.end.min(src.end);
+                let after = range.start.max(src.end)..range.end;
+
+                if !inside.is_empty() {
+                    claimed.push((Some(index), inside));
+                }
+                rest.extend(
+                    [before, after]
+                        .into_iter()
+                        .filter(|piece| !piece.is_empty()),
+                );
+            }
+
+            unclaimed = rest;
+        }
+
+        claimed.extend(unclaimed.into_iter().map(|range| (None, range)));
+        claimed
+    }
+
+    /// Maps a whole range at once, splitting it wherever a line starts or
+    /// ends. Pieces no line covers pass through unchanged.
+    pub fn look_up_range(&self, range: Range<u128>) -> Vec<Range<u128>> {
+        self.claims(range)
+            .into_iter()
+            .map(|(line, range)| match line {
+                Some(line) => self.lines[line].map_range(range),
+                None => range,
+            })
+            .collect()
+    }
+
+    /// Every range of numbers that maps into `range`.
+    pub fn look_up_range_rev(&self, range: Range<u128>) -> Vec<Range<u128>> {
+        let claimed_by = |line: Option<usize>, src: Range<u128>| {
+            self.claims(src)
+                .into_iter()
+                .filter(move |(by, _)| *by == line)
+                .map(|(_, piece)| piece)
+        };
+
+        let mut found = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter_map(|(index, line)| Some((index, line.unmap_range(range.clone())?)))
+            .flat_map(|(index, src)| claimed_by(Some(index), src))
+            .chain(claimed_by(None, range.clone()))
+            .collect::<Vec<_>>();
+        found.sort_unstable_by_key(|range| range.start);
+        found
+    }
+}
+
+/// How to read the `seeds:` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seeds {
+    /// Every number is a seed, e.g. part 1.
+    Numbers,
+    /// Numbers come in pairs of start and length, e.g. part 2.
+    Ranges,
+}
+
+pub struct Almanac {
+    seed_ranges: Vec<Range<u128>>,
+    categories: Vec<AlmanacCategory>,
+    graph: CategoryGraph,
+    /// Indices of the categories leading from seed to location.
+    chain: Vec<usize>,
+}
+
+impl Almanac {
+    /// Checks the categories link `seed` to `location`, in whatever order
+    /// the almanac lists them.
+    pub fn new(
+        seed_ranges: Vec<Range<u128>>,
+        categories: Vec<AlmanacCategory>,
+    ) -> Result<Self, AocError> {
+        let graph = CategoryGraph::new(
+            categories
+                .iter()
+                .map(|category| (category.src.as_str(), category.dst.as_str())),
+        )?;
+        let chain = graph.path("seed", "location")?;
+
+        Ok(Self {
+            seed_ranges,
+            categories,
+            graph,
+            chain,
+        })
+    }
+
+    /// Seeds of the almanac, a single seed as a range of length 1.
+    pub fn seed_ranges(&self) -> &[Range<u128>] {
+        &self.seed_ranges
+    }
+
+    fn chain(&self) -> impl DoubleEndedIterator<Item = &AlmanacCategory> {
+        self.chain.iter().map(|&index| &self.categories[index])
+    }
+
+    /// Categories leading from `from` to `to`.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<&AlmanacCategory>, AocError> {
+        Ok(self
+            .graph
+            .path(from, to)?
+            .into_iter()
+            .map(|index| &self.categories[index])
+            .collect())
+    }
+
+    /// Maps `n` of category `from` to category `to`, e.g. soil 81 to humidity.
+    pub fn map(&self, from: &str, n: u64, to: &str) -> Result<u64, AocError> {
+        Ok(self
+            .path(from, to)?
+            .into_iter()
+            .fold(n, |n, category| category.look_up(n)))
+    }
+
+    /// Maps a whole range of category `from` to category `to`.
+    pub fn map_range(
+        &self,
+        from: &str,
+        range: Range<u128>,
+        to: &str,
+    ) -> Result<Vec<Range<u128>>, AocError> {
+        Ok(self
+            .path(from, to)?
+            .into_iter()
+            .fold(vec![range], |ranges, category| {
+                ranges
+                    .into_iter()
+                    .flat_map(|range| category.look_up_range(range))
+                    .collect()
+            }))
+    }
+
+    fn seed_location(&self, seed: u64) -> u64 {
+        self.chain().fold(seed, |n, category| category.look_up(n))
+    }
+
+    /// Ranges of locations the seed ranges end up in.
+    pub fn location_ranges(&self) -> Vec<Range<u128>> {
+        self.chain()
+            .fold(self.seed_ranges.clone(), |ranges, category| {
+                ranges
+                    .into_iter()
+                    .flat_map(|range| category.look_up_range(range))
+                    .collect()
+            })
+    }
+
+    /// Every category composed into a single seed to location map.
+    pub fn composed(&self) -> PiecewiseMap {
+        self.chain()
+            .map(AlmanacCategory::piecewise)
+            .reduce(|map, next| map.then(&next))
+            .unwrap_or_else(|| PiecewiseMap::new([]))
+    }
+
+    pub fn closest_location(&self) -> Option<u64> {
+        let composed = self.composed();
+        self.seed_ranges
+            .iter()
+            .filter_map(|range| composed.min(range.clone()))
+            .min()
+    }
+
+    /// Every number that ends up at `location`, seed or not.
+    pub fn seed_location_rev(&self, location: u64) -> Vec<u64> {
+        self.chain().rev().fold(vec![location], |found, category| {
+            let mut found = found
+                .into_iter()
+                .flat_map(|m| category.look_up_rev(m))
+                .collect::<Vec<_>>();
+            found.sort_unstable();
+            found.dedup();
+            found
+        })
+    }
+
+    /// Seeds of the almanac that end up at `location`.
+    pub fn seeds_for(&self, location: u64) -> Vec<u64> {
+        self.seed_location_rev(location)
+            .into_iter()
+            .filter(|seed| {
+                self.seed_ranges
+                    .iter()
+                    .any(|range| range.contains(&u128::from(*seed)))
+            })
+            .collect()
+    }
+
+    /// Every range of numbers that ends up in `locations`, seed or not.
+    pub fn location_range_rev(&self, locations: Range<u128>) -> Vec<Range<u128>> {
+        self.chain().rev().fold(vec![locations], |found, category| {
+            found
+                .into_iter()
+                .flat_map(|range| category.look_up_range_rev(range))
+                .collect()
+        })
+    }
+
+    /// Seed ranges of the almanac that end up in `locations`.
+    pub fn seed_ranges_for(&self, locations: Range<u128>) -> Vec<Range<u128>> {
+        self.location_range_rev(locations)
+            .into_iter()
+            .flat_map(|found| {
+                self.seed_ranges.iter().filter_map(move |seeds| {
+                    let overlap = found.start.max(seeds.start)..found.end.min(seeds.end);
+                    (!overlap.is_empty()).then_some(overlap)
+                })
+            })
+            .collect()
+    }
+
+    /// [`Self::closest_location`] found backwards: the smallest `n` such
+    /// that some seed ends up in `0..=n`, by binary search.
+    pub fn closest_location_rev(&self) -> Option<u64> {
+        let reachable = |n: u64| !self.seed_ranges_for(0..u128::from(n) + 1).is_empty();
+        if !reachable(u64::MAX) {
+            return None;
+        }
+
+        let (mut low, mut high) = (0, u64::MAX);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if reachable(mid) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        Some(low)
+    }
+
+    /// How `seed` gets to its location, category by category.
+    pub fn explain(&self, seed: u64) -> Trace<'_, u64> {
+        let mut n = seed;
+        let steps = self
+            .chain()
+            .map(|category| {
+                let line = category.line_for(n);
+                let before = n;
+                n = category.look_up(n);
+                Step {
+                    category,
+                    line,
+                    before,
+                    after: n,
+                }
+            })
+            .collect();
+
+        let trace = Trace(steps);
+        trace.emit();
+        trace
+    }
+
+    /// How the pieces of `seeds` get to their locations, one trace per
+    /// piece that is mapped as a whole all the way, ordered by seed.
+    pub fn explain_range(&self, seeds: Range<u128>) -> Vec<Trace<'_, Range<u128>>> {
+        let traces = self
+            .chain()
+            .fold(vec![(seeds, Trace(Vec::new()))], |traces, category| {
+                traces
+                    .into_iter()
+                    .flat_map(|(range, trace)| {
+                        category
+                            .claims(range)
+                            .into_iter()
+                            .map(move |(index, piece)| {
+                                let line = index.map(|index| &category.lines[index]);
+                                let after = line.map_or_else(
+                                    || piece.clone(),
+                                    |line| line.map_range(piece.clone()),
+                                );
+
+                                let Trace(mut steps) = trace.narrow(piece.clone());
+                                steps.push(Step {
+                                    category,
+                                    line,
+                                    before: piece,
+                                    after: after.clone(),
+                                });
+                                (after, Trace(steps))
+                            })
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+        let mut traces = traces
+            .into_iter()
+            .map(|(_, trace)| trace)
+            .collect::<Vec<_>>();
+        traces.sort_by_key(|Trace(steps)| steps.first().map(|step| step.before.start));
+        traces.iter().for_each(Trace::emit);
+        traces
+    }
+
+    /// Reference for [`Self::closest_location`] that looks up every seed on
+    /// its own, which takes minutes on a real input.
+    ///
+    /// Reports seeds as they are done through [`Progress`].
+    pub fn closest_location_brute_force(&self) -> Option<u64> {
+        // seeds looked up between two progress updates
+        const BLOCK: u128 = 1 << 16;
+
+        let seeds = self.seed_ranges.iter().map(|range| range.end - range.start);
+        let progress = Progress::new(seeds.sum::<u128>().try_into().unwrap_or(u64::MAX), "seeds");
+
+        let closest = self
+            .seed_ranges
+            .par_iter()
+            .flat_map(|range| {
+                let Range { start, end } = range.clone();
+                (0..(end - start).div_ceil(BLOCK))
+                    .into_par_iter()
+                    .map(move |block| start + block * BLOCK..end.min(start + (block + 1) * BLOCK))
+            })
+            .filter_map(|block| {
+                let seeds = block.end - block.start;
+                // seeds fit in u64, parse_almanac reports any that don't
+                let closest = block.map(|seed| self.seed_location(seed as u64)).min();
+                progress.inc(seeds as u64);
+                closest
+            })
+            .min();
+
+        progress.finish();
+        closest
+    }
+}
+
+mod parsing {
+    use super::*;
+    use crate::error::AlmanacIssues;
+
+    use aoc_core::parsing::{parse, IResult, Span};
+    use nom::{
+        character::complete::{alphanumeric1, line_ending, multispace0, space1, u64},
+        combinator::{consumed, cut, map},
+        multi::separated_list1,
+        sequence::{preceded, separated_pair, terminated, tuple},
+        Parser,
+    };
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    /// Start, length and span of a seed range.
+    type SeedRange = (u64, u64, SourceSpan);
+
+    fn seed_range<'a>(seeds: Seeds) -> impl FnMut(Span<'a>) -> IResult<'a, SeedRange> {
+        move |input| match seeds {
+            Seeds::Numbers => map(consumed(u64), |(span, start): (Span, _)| {
+                (start, 1, to_source_span(span))
+            })
+            .context("seed")
+            .parse(input),
+            Seeds::Ranges => map(
+                consumed(tuple((u64, cut(preceded(space1, u64))))),
+                |(span, (start, len)): (Span, _)| (start, len, to_source_span(span)),
+            )
+            .context("seed range")
+            .parse(input),
+        }
+    }
+
+    fn almanac<'a>(
+        seeds: Seeds,
+    ) -> impl FnMut(Span<'a>) -> IResult<'a, (Vec<SeedRange>, Vec<AlmanacCategory>)> {
+        move |input| {
+            let seeds = preceded(
+                tag("seeds: "),
+                cut(separated_list1(space1, seed_range(seeds))),
+            )
+            .context("seeds");
+            // once a line starts with a number it has to be a complete map line
+            let line = map(
+                consumed(tuple((
+                    terminated(u64, space1),
+                    cut(terminated(u64, space1)),
+                    cut(u64),
+                ))),
+                |(span, (dst, src, len)): (Span, _)| {
+                    AlmanacLine::new(dst, src, len).with_span(to_source_span(span))
+                },
+            )
+            .context("map line");
+            let lines = separated_list1(line_ending, line);
+            // `seed-to-soil map:`
+            let header = terminated(
+                separated_pair(alphanumeric1, tag("-to-"), cut(alphanumeric1)),
+                tuple((cut(tag(" map:")), cut(line_ending))),
+            );
+            let category = map(
+                tuple((header.context("category header"), cut(lines))),
+                |((from, to), lines): ((Span, Span), _)| {
+                    AlmanacCategory::new(from.fragment(), to.fragment(), lines)
+                },
+            )
+            .context("category");
+            let categories = separated_list1(tuple((line_ending, line_ending)), category);
+
+            tuple((
+                terminated(seeds, line_ending),
+                preceded(line_ending, terminated(categories, multispace0)),
+            ))(input)
+        }
+    }
+
+    fn to_source_span(span: Span) -> SourceSpan {
+        (span.location_offset(), span.fragment().len()).into()
+    }
+
+    /// Parses and validates an almanac, reporting every issue at once.
+    pub fn parse_almanac(input: &str, seeds: Seeds) -> Result<Almanac, AocError> {
+        let (seeds, categories) = parse("almanac", input, almanac(seeds))?;
+
+        let issues = seeds
+            .iter()
+            .filter(|&&(start, len, _)| overflows(start, len))
+            .map(|&(start, len, span)| AlmanacIssue::Overflow {
+                what: "seed",
+                start,
+                len,
+                span,
+            })
+            .chain(categories.iter().flat_map(AlmanacCategory::issues))
+            .collect::<Vec<_>>();
+        if !issues.is_empty() {
+            return Err(AlmanacIssues {
+                src: input.into(),
+                issues,
+            }
+            .into());
+        }
+
+        let seed_ranges = seeds
+            .into_iter()
+            .map(|(start, len, _)| u128::from(start)..u128::from(start) + u128::from(len))
+            .collect();
+        Almanac::new(seed_ranges, categories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+    use itertools::Itertools;
+    use miette::IntoDiagnostic;
+
+    use super::*;
+    use crate::{piecewise::END, Day05};
+
+    // dst_start src_start length
+    // xxx_start <= x < xxx_start + length
+    //
+    // 50 98 2:
+    // src: 98..(98 + 2) = 98 <= x < 100 = {98, 99}
+    // dst: 50..(50 + 2) = 50 <= x < 52  = {50, 51}
+    //
+    // 52 50 48:
+    // src: 50..(50 + 48) = 50 <= x < 98  = {50..97}
+    // dst: 52..(52 + 48) = 52 <= x < 100 = {52..99}
+
+    #[test_log::test]
+    fn test_parse_almanac() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+
+        let parsed = parse_almanac(&example.input, Seeds::Numbers).into_diagnostic()?;
+        assert_eq!(vec![79..80, 14..15, 55..56, 13..14], parsed.seed_ranges);
+        assert_eq!(parsed.categories.len(), 7);
+
+        let parsed = parse_almanac(&example.input, Seeds::Ranges).into_diagnostic()?;
+        assert_eq!(vec![79..93, 55..68], parsed.seed_ranges);
+        assert_eq!(parsed.categories.len(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_almanac_error() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::One)?;
+        let input = example.input.replace("52 50 48", "52 50 x8");
+        let Err(AocError::Core(aoc_core::Error::Parse(err))) =
+            parse_almanac(&input, Seeds::Numbers)
+        else {
+            panic!("malformed map line should not parse");
+        };
+
+        assert_eq!(input.find("x8"), Some(err.span.offset()));
+        assert_eq!("an ascii digit", err.expected);
+        assert_eq!(
+            Some("while parsing category > map line"),
+            err.help.as_deref()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_look_up_range() {
+        // 50..98 -> 52..100, 98..100 -> 50..52
+        let category = AlmanacCategory::new(
+            "seed",
+            "soil",
+            vec![AlmanacLine::new(50, 98, 2), AlmanacLine::new(52, 50, 48)],
+        );
+
+        let mut mapped = category.look_up_range(40..110);
+        mapped.sort_by_key(|range| range.start);
+
+        assert_eq!(vec![40..50, 50..52, 52..100, 100..110], mapped);
+        assert_eq!(vec![55..60], category.look_up_range(53..58));
+        assert_eq!(vec![0..10], category.look_up_range(0..10));
+    }
+
+    #[test]
+    fn test_closest_location_matches_brute_force() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let almanac = parse_almanac(&example.input, Seeds::Ranges)?;
+
+        assert_eq!(Some(46), almanac.closest_location_brute_force());
+        assert_eq!(
+            almanac.closest_location_brute_force(),
+            almanac.closest_location()
+        );
+
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_composed_matches_fold() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let almanac = parse_almanac(&example.input, Seeds::Ranges)?;
+        let composed = almanac.composed();
+        tracing::debug!("seed to location:\n{composed}");
+
+        for seed in 0..200 {
+            assert_eq!(
+                almanac.seed_location(seed),
+                composed.look_up(seed),
+                "{seed}"
+            );
+        }
+
+        let closest_range_start = almanac
+            .location_ranges()
+            .iter()
+            .map(|range| range.start)
+            .min();
+        assert_eq!(
+            closest_range_start,
+            almanac.closest_location().map(u128::from)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_look_up_rev() {
+        // 98..100 -> 50..52, 50..98 -> 52..100
+        let category = AlmanacCategory::new(
+            "seed",
+            "soil",
+            vec![AlmanacLine::new(50, 98, 2), AlmanacLine::new(52, 50, 48)],
+        );
+
+        assert_eq!(vec![98], category.look_up_rev(50));
+        assert_eq!(vec![53], category.look_up_rev(55));
+        assert_eq!(vec![10], category.look_up_rev(10));
+        // 99 comes from 97, while 99 itself is mapped away
+        assert_eq!(vec![97], category.look_up_rev(99));
+        // 100 comes from itself, and nothing maps to it
+        assert_eq!(vec![100], category.look_up_rev(100));
+
+        assert_eq!(vec![45..50, 98..100], category.look_up_range_rev(45..52));
+        assert_eq!(vec![97..98, 100..105], category.look_up_range_rev(99..105));
+    }
+
+    #[test]
+    fn test_seeds_for() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let almanac = parse_almanac(&example.input, Seeds::Ranges)?;
+
+        // the puzzle text says seed 82 ends up at location 46
+        assert_eq!(vec![82], almanac.seeds_for(46));
+        assert!(almanac.seed_location_rev(46).contains(&82));
+        assert_eq!(vec![82..83], almanac.seed_ranges_for(46..47));
+        assert_eq!(Some(46), almanac.closest_location_rev());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_between_categories() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let almanac = parse_almanac(&example.input, Seeds::Ranges)?;
+
+        // seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78, humidity 78
+        assert_eq!(78, almanac.map("soil", 81, "humidity")?);
+        assert_eq!(82, almanac.map("seed", 79, "location")?);
+        assert_eq!(74, almanac.map("light", 74, "light")?);
+        assert_eq!(vec![78..79], almanac.map_range("soil", 81..82, "humidity")?);
+        assert!(matches!(
+            almanac.map("humidity", 78, "soil"),
+            Err(AocError::MissingLink { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_categories_in_any_order() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let (seeds, categories) = example.input.split_once("\n\n").unwrap();
+        let reversed = categories
+            .trim_end()
+            .split("\n\n")
+            .collect_vec()
+            .into_iter()
+            .rev()
+            .join("\n\n");
+        let almanac = parse_almanac(&format!("{seeds}\n\n{reversed}\n"), Seeds::Ranges)?;
+
+        assert_eq!(Some(46), almanac.closest_location());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_broken_chain() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+
+        let input = example.input.replace("water-to-light", "water-to-sunlight");
+        let Err(AocError::MissingLink { reachable, .. }) = parse_almanac(&input, Seeds::Ranges)
+        else {
+            panic!("seed should not lead to location");
+        };
+        assert_eq!("`soil`, `fertilizer`, `water`, `sunlight`", reachable);
+
+        let input = example
+            .input
+            .replace("light-to-temperature", "seed-to-soil");
+        assert!(matches!(
+            parse_almanac(&input, Seeds::Ranges),
+            Err(AocError::DuplicateCategory { .. })
+        ));
+
+        let input = example
+            .input
+            .replace("humidity-to-location", "humidity-to-light");
+        assert!(matches!(
+            parse_almanac(&input, Seeds::Ranges),
+            Err(AocError::CategoryCycle { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_past_i64() {
+        let line = AlmanacLine::new(u64::MAX - 10, 10, 10);
+
+        assert_eq!(Some(u64::MAX - 10), line.try_map(10));
+        assert_eq!(Some(u64::MAX - 1), line.try_map(19));
+        assert_eq!(None, line.try_map(20));
+        assert_eq!(Some(19), line.try_unmap(u64::MAX - 1));
+        assert_eq!(None, line.try_unmap(u64::MAX));
+        assert_eq!(None, AlmanacLine::new(0, u64::MAX - 1, 1).try_map(0));
+    }
+
+    #[test]
+    fn test_issues() {
+        let category = AlmanacCategory::new(
+            "seed",
+            "soil",
+            vec![
+                AlmanacLine::new(0, 10, 10).with_span(0..1),
+                AlmanacLine::new(0, 30, 0).with_span(1..2),
+                AlmanacLine::new(0, 5, 20).with_span(2..3),
+                AlmanacLine::new(u64::MAX, 50, 1).with_span(3..4),
+                AlmanacLine::new(0, 21, 2).with_span(4..5),
+                AlmanacLine::new(u64::MAX - 1, 60, 3).with_span(5..6),
+            ],
+        );
+
+        assert_eq!(
+            vec![
+                AlmanacIssue::EmptyLine {
+                    category: "seed-to-soil".to_string(),
+                    span: (1..2).into(),
+                },
+                AlmanacIssue::Overflow {
+                    what: "destination",
+                    start: u64::MAX - 1,
+                    len: 3,
+                    span: (5..6).into(),
+                },
+                AlmanacIssue::Overlap {
+                    category: "seed-to-soil".to_string(),
+                    overlap: 10..20,
+                    span: (0..1).into(),
+                    other: (2..3).into(),
+                },
+                AlmanacIssue::Overlap {
+                    category: "seed-to-soil".to_string(),
+                    overlap: 21..23,
+                    span: (4..5).into(),
+                    other: (2..3).into(),
+                },
+            ],
+            category.issues()
+        );
+    }
+
+    #[test_log::test]
+    fn test_ranges_ending_at_u64_max() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let input = example
+            .input
+            .replace("seeds: 79 14", "seeds: 79 18446744073709551537")
+            .replace("50 98 2", "50 98 2\n18446744073709551614 0 2");
+        let almanac = parse_almanac(&input, Seeds::Ranges)?;
+        assert_eq!(79..END, almanac.seed_ranges()[0]);
+
+        let category = &almanac.categories[0];
+        assert_eq!(u64::MAX, category.look_up(1));
+        assert_eq!(
+            vec![u128::from(u64::MAX - 1)..END, 2..8],
+            category.look_up_range(0..8)
+        );
+        let max = u128::from(u64::MAX);
+        assert_eq!(vec![1..2, max..END], category.look_up_range_rev(max..END));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_reports_every_issue() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        assert!(parse_almanac(&example.input, Seeds::Ranges)?
+            .categories
+            .iter()
+            .all(|category| category.issues().is_empty()));
+
+        let input = example
+            .input
+            .replace("seeds: 79 14", "seeds: 79 18446744073709551615")
+            .replace("52 50 48", "52 50 48\n60 90 0");
+        let Err(AocError::Invalid(invalid)) = parse_almanac(&input, Seeds::Ranges) else {
+            panic!("invalid almanac should be rejected");
+        };
+
+        let offsets = invalid
+            .issues
+            .iter()
+            .map(|issue| match issue {
+                AlmanacIssue::Overflow { span, .. } | AlmanacIssue::EmptyLine { span, .. } => {
+                    span.offset()
+                }
+                AlmanacIssue::Overlap { .. } => unreachable!("{issue}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![input.find("79").unwrap(), input.find("60 90 0").unwrap()],
+            offsets
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_odd_seed_count() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let input = example.input.replace("55 13", "55");
+
+        let Err(AocError::Core(aoc_core::Error::Parse(err))) = parse_almanac(&input, Seeds::Ranges)
+        else {
+            panic!("seed without length should not parse");
+        };
+        assert_eq!(input.find("\n\n"), Some(err.span.offset()));
+
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_explain() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let almanac = parse_almanac(&example.input, Seeds::Ranges)?;
+
+        // seed 79, soil 81, fertilizer 81, water 81, light 74, temperature 78,
+        // humidity 78, location 82
+        let Trace(steps) = almanac.explain(79);
+        let values = steps
+            .iter()
+            .map(|step| (step.before, step.after))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (79, 81),
+                (81, 81),
+                (81, 81),
+                (81, 74),
+                (74, 78),
+                (78, 78),
+                (78, 82)
+            ],
+            values
+        );
+        let lines = steps
+            .iter()
+            .map(|step| step.line.map(ToString::to_string))
+            .collect::<Vec<_>>();
+        assert_eq!(Some("52 50 48"), lines[0].as_deref());
+        assert_eq!(None, lines[1]);
+        assert_eq!("seed-to-soil", steps[0].category.name());
+        assert!(almanac
+            .explain(79)
+            .to_string()
+            .contains("soil-to-fertilizer       pass-through      81     81"));
+
+        Ok(())
+    }
+
+    #[test_log::test]
+    fn test_explain_range() -> miette::Result<()> {
+        let example = Example::load(Day05::DAY, Part::Two)?;
+        let almanac = parse_almanac(&example.input, Seeds::Ranges)?;
+
+        let traces = almanac.explain_range(79..93);
+        let seeds = traces
+            .iter()
+            .map(|Trace(steps)| steps[0].before.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(79, seeds[0].start);
+        assert_eq!(93, seeds[seeds.len() - 1].end);
+        assert!(seeds.windows(2).all(|pair| pair[0].end == pair[1].start));
+
+        for Trace(steps) in &traces {
+            assert!(steps.windows(2).all(|pair| pair[0].after == pair[1].before));
+            let seeds = &steps[0].before;
+            let location = &steps[steps.len() - 1].after;
+            let seed = u64::try_from(seeds.start).into_diagnostic()?;
+            assert_eq!(u128::from(almanac.explain(seed).0[6].after), location.start);
+            assert_eq!(seeds.end - seeds.start, location.end - location.start);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+//! Part 2 the slow way, looking up every seed on its own, to check
+//! `Almanac::closest_location` against; takes minutes on a real input.
+//!
+//! Takes the same `--input` and `--progress` as the `part2` binary; with
+//! `--progress` it draws a bar of the seeds done when run in a terminal.
+
+use aoc_core::{runner, Part};
+use clap::Parser;
+use day_05::{
+    almanac::{parse_almanac, Seeds},
+    Day05,
+};
+use miette::miette;
+
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let input = runner::Args::parse().read_input::<Day05>(Part::Two)?;
+    let almanac = parse_almanac(&input, Seeds::Ranges)?;
+    let closest = almanac
+        .closest_location_brute_force()
+        .ok_or_else(|| miette!("the almanac lists no seeds"))?;
+
+    println!("{closest}");
+    Ok(())
+}
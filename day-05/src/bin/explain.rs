@@ -0,0 +1,116 @@
+//! Shows how seeds get to their locations, category by category, e.g.
+//! `cargo run -p day-05 --bin explain -- 79 82..96`.
+//!
+//! Without seeds it explains the seeds ending up at the closest location.
+//! Run with `RUST_LOG=day_05=debug` to get every step as a tracing event too.
+
+use std::{ops::Range, str::FromStr};
+
+use aoc_core::{input::Source, Part, Solution};
+use clap::Parser;
+use day_05::{
+    almanac::{parse_almanac, Seeds},
+    piecewise::END,
+    Day05,
+};
+use miette::{miette, IntoDiagnostic};
+
+#[derive(Parser, Debug)]
+#[command(about = "Explains how seeds get to their locations")]
+struct Args {
+    /// Seeds (`79`) or seed ranges (`79..93`)
+    /// [default: the seeds of the closest location]
+    seeds: Vec<SeedArg>,
+
+    /// Part whose reading of the `seeds:` line to use, for the default seeds
+    #[arg(short, long, default_value = "2")]
+    part: Part,
+
+    /// Puzzle input file, `-` for stdin
+    /// [default: day-05/input.txt in the workspace]
+    #[arg(short, long)]
+    input: Option<Source>,
+}
+
+#[derive(Debug, Clone)]
+struct SeedArg(Range<u128>);
+
+impl SeedArg {
+    fn single(seed: u64) -> Self {
+        let seed = u128::from(seed);
+        Self(seed..seed + 1)
+    }
+}
+
+impl FromStr for SeedArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |n: &str| {
+            n.parse::<u64>()
+                .map_err(|err| format!("`{n}` is not a seed: {err}"))
+        };
+        // ranges are exclusive, so they may end one past u64::MAX
+        let bound = |n: &str| match n.parse::<u128>() {
+            Ok(end) if end <= END => Ok(end),
+            _ => Err(format!(
+                "`{n}` is not the end of a range: ranges end by 2^64"
+            )),
+        };
+
+        match s.split_once("..") {
+            Some((start, end)) => {
+                let range = u128::from(number(start)?)..bound(end)?;
+                if range.is_empty() {
+                    return Err(format!("`{s}` is empty, a range needs start < end"));
+                }
+                Ok(Self(range))
+            }
+            None => number(s).map(Self::single),
+        }
+    }
+}
+
+fn main() -> miette::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let args = Args::parse();
+    let input = match args.input {
+        Some(source) => source.read().into_diagnostic()?,
+        None => Day05::input(args.part)?,
+    };
+    let seeds = match args.part {
+        Part::One => Seeds::Numbers,
+        Part::Two => Seeds::Ranges,
+    };
+    let almanac = parse_almanac(&input, seeds)?;
+
+    let seeds = if args.seeds.is_empty() {
+        let closest = almanac
+            .closest_location()
+            .ok_or_else(|| miette!("the almanac lists no seeds"))?;
+        println!("closest location {closest}\n");
+        almanac
+            .seeds_for(closest)
+            .into_iter()
+            .map(SeedArg::single)
+            .collect()
+    } else {
+        args.seeds
+    };
+
+    for SeedArg(range) in seeds {
+        if range.end - range.start == 1 {
+            // starts are parsed as u64
+            let seed = range.start as u64;
+            println!("seed {seed}\n{}", almanac.explain(seed));
+        } else {
+            for trace in almanac.explain_range(range.clone()) {
+                let first = &trace.0[0].before;
+                println!("seeds {first:?} of {range:?}\n{trace}");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,13 +1,85 @@
-use miette::Diagnostic;
+use std::{ops::Range, sync::Arc};
+
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
+    #[diagnostic(transparent)]
+    Core(#[from] aoc_core::Error),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Invalid(#[from] AlmanacIssues),
+
+    #[error("unknown category `{name}`")]
+    #[diagnostic(code(day_05::unknown_category), help("the almanac has {known}"))]
+    UnknownCategory { name: String, known: String },
 
-    #[error("cannot parse Almanac")]
-    #[diagnostic(code(aoc::parse_almanac_error))]
-    ParseAlmanacError,
+    #[error("no chain of maps leads from `{from}` to `{to}`")]
+    #[diagnostic(
+        code(day_05::missing_link),
+        help("`{from}` only leads to [{reachable}]")
+    )]
+    MissingLink {
+        from: String,
+        to: String,
+        reachable: String,
+    },
+
+    #[error("maps lead in a cycle through {categories}")]
+    #[diagnostic(code(day_05::category_cycle))]
+    CategoryCycle { categories: String },
+
+    #[error("`{src}-to-{dst} map` is listed more than once")]
+    #[diagnostic(code(day_05::duplicate_category))]
+    DuplicateCategory { src: String, dst: String },
+}
+
+/// Numbers of an almanac that parse but make no sense as a map.
+#[derive(Error, Diagnostic, Debug, PartialEq)]
+pub enum AlmanacIssue {
+    #[error("`{category}` map has a line of length 0")]
+    #[diagnostic(code(day_05::empty_line), help("drop the line or give it a length"))]
+    EmptyLine {
+        category: String,
+        #[label("maps nothing")]
+        span: SourceSpan,
+    },
+
+    #[error("lines of `{category}` map overlap on {}..{}", .overlap.start, .overlap.end)]
+    #[diagnostic(
+        code(day_05::overlapping_lines),
+        help("only the first of both lines would apply to those numbers")
+    )]
+    Overlap {
+        category: String,
+        overlap: Range<u128>,
+        #[label("this line")]
+        span: SourceSpan,
+        #[label("overlaps this one")]
+        other: SourceSpan,
+    },
+
+    #[error("{what} range {start}..{start}+{len} does not fit in u64")]
+    #[diagnostic(code(day_05::overflow))]
+    Overflow {
+        what: &'static str,
+        start: u64,
+        len: u64,
+        #[label("ends past u64::MAX")]
+        span: SourceSpan,
+    },
+}
+
+/// Every issue of an almanac, reported together.
+#[derive(Error, Diagnostic, Debug)]
+#[error("almanac has {} invalid ranges", .issues.len())]
+#[diagnostic(code(day_05::invalid_almanac))]
+pub struct AlmanacIssues {
+    #[source_code]
+    pub src: Arc<str>,
+    #[related]
+    pub issues: Vec<AlmanacIssue>,
 }
@@ -0,0 +1,88 @@
+//! Traces of seeds through the categories of an almanac, for when an answer
+//! is off and it is not obvious which line is to blame.
+
+use std::{
+    fmt::{self, Debug, Display},
+    ops::Range,
+};
+
+use tracing::debug;
+
+use crate::almanac::{AlmanacCategory, AlmanacLine};
+
+/// What one category did to a value, or to a range of values.
+#[derive(Debug, Clone)]
+pub struct Step<'a, T> {
+    pub category: &'a AlmanacCategory,
+    /// Line that mapped the value, `None` if it passed through unchanged.
+    pub line: Option<&'a AlmanacLine>,
+    pub before: T,
+    pub after: T,
+}
+
+/// Steps of a seed, or of a range of seeds mapped as a whole, from seed to
+/// location.
+#[derive(Debug, Clone)]
+pub struct Trace<'a, T>(pub Vec<Step<'a, T>>);
+
+impl<T: Debug> Trace<'_, T> {
+    /// Emits every step as a `debug` event.
+    pub fn emit(&self) {
+        for step in &self.0 {
+            debug!(
+                category = %step.category.name(),
+                line = %line_or_pass_through(step.line),
+                before = ?step.before,
+                after = ?step.after,
+                "explain"
+            );
+        }
+    }
+}
+
+impl<'a> Trace<'a, Range<u128>> {
+    /// Trace of the part of this trace that ends up in `range`, which has to
+    /// lie within where the last step ends up.
+    pub(crate) fn narrow(&self, mut range: Range<u128>) -> Self {
+        let mut steps = self.0.clone();
+        for step in steps.iter_mut().rev() {
+            let start = step.before.start + (range.start - step.after.start);
+            step.before = start..start + (range.end - range.start);
+            step.after = range;
+            range = step.before.clone();
+        }
+        Self(steps)
+    }
+}
+
+fn line_or_pass_through(line: Option<&AlmanacLine>) -> String {
+    line.map_or_else(|| "pass-through".to_string(), ToString::to_string)
+}
+
+/// One row per step, columns aligned.
+impl<T: Debug> Display for Trace<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = ["category", "line", "before", "after"].map(String::from);
+        let rows = std::iter::once(header)
+            .chain(self.0.iter().map(|step| {
+                [
+                    step.category.name(),
+                    line_or_pass_through(step.line),
+                    format!("{:?}", step.before),
+                    format!("{:?}", step.after),
+                ]
+            }))
+            .collect::<Vec<_>>();
+        let width = |column: usize| rows.iter().map(|row| row[column].len()).max();
+        let [category, line, before, after] = [0, 1, 2, 3].map(|column| width(column).unwrap_or(0));
+
+        for row in &rows {
+            writeln!(
+                f,
+                "{:<category$}  {:<line$}  {:>before$}  {:>after$}",
+                row[0], row[1], row[2], row[3]
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//! Categories of an almanac as a graph, one edge per `src-to-dst map`, so
+//! numbers can be mapped between any two categories a chain of maps links.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use petgraph::{
+    algo::{astar, kosaraju_scc},
+    graph::{DiGraph, NodeIndex},
+    visit::Dfs,
+};
+
+use crate::error::AocError;
+
+#[derive(Debug, Clone)]
+pub struct CategoryGraph {
+    /// Nodes are category names, edges the index of the map between them.
+    graph: DiGraph<String, usize>,
+    nodes: HashMap<String, NodeIndex>,
+}
+
+impl CategoryGraph {
+    /// Builds the graph of `(src, dst)` maps, in almanac order.
+    ///
+    /// A map listed twice or maps leading back to where they started make
+    /// "the" path between two categories ambiguous, so both are errors.
+    pub fn new<'a>(maps: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Self, AocError> {
+        let mut graph = Self {
+            graph: DiGraph::new(),
+            nodes: HashMap::new(),
+        };
+
+        for (index, (src, dst)) in maps.into_iter().enumerate() {
+            let from = graph.node(src);
+            let to = graph.node(dst);
+            if graph.graph.find_edge(from, to).is_some() {
+                return Err(AocError::DuplicateCategory {
+                    src: src.to_string(),
+                    dst: dst.to_string(),
+                });
+            }
+            graph.graph.add_edge(from, to, index);
+        }
+
+        let cycle = kosaraju_scc(&graph.graph).into_iter().find(|component| {
+            component.len() > 1 || graph.graph.contains_edge(component[0], component[0])
+        });
+        if let Some(component) = cycle {
+            return Err(AocError::CategoryCycle {
+                categories: graph.names(component.into_iter().rev()),
+            });
+        }
+
+        Ok(graph)
+    }
+
+    fn node(&mut self, name: &str) -> NodeIndex {
+        *self
+            .nodes
+            .entry(name.to_string())
+            .or_insert_with(|| self.graph.add_node(name.to_string()))
+    }
+
+    fn find(&self, name: &str) -> Result<NodeIndex, AocError> {
+        self.nodes
+            .get(name)
+            .copied()
+            .ok_or_else(|| AocError::UnknownCategory {
+                name: name.to_string(),
+                known: self.names(self.graph.node_indices()),
+            })
+    }
+
+    fn names(&self, nodes: impl IntoIterator<Item = NodeIndex>) -> String {
+        nodes
+            .into_iter()
+            .map(|node| format!("`{}`", self.graph[node]))
+            .join(", ")
+    }
+
+    /// Indices of the maps leading from `from` to `to`, shortest chain first.
+    pub fn path(&self, from: &str, to: &str) -> Result<Vec<usize>, AocError> {
+        let start = self.find(from)?;
+        let goal = self.find(to)?;
+
+        let Some((_, nodes)) = astar(&self.graph, start, |node| node == goal, |_| 1, |_| 0) else {
+            let mut dfs = Dfs::new(&self.graph, start);
+            let reachable = std::iter::from_fn(|| dfs.next(&self.graph)).skip(1);
+
+            return Err(AocError::MissingLink {
+                from: from.to_string(),
+                to: to.to_string(),
+                reachable: self.names(reachable),
+            });
+        };
+
+        Ok(nodes
+            .into_iter()
+            .tuple_windows()
+            .filter_map(|(src, dst)| self.graph.find_edge(src, dst))
+            .map(|edge| self.graph[edge])
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() -> miette::Result<()> {
+        let graph = CategoryGraph::new([
+            ("seed", "soil"),
+            ("soil", "water"),
+            ("seed", "light"),
+            ("water", "light"),
+            ("light", "location"),
+        ])?;
+
+        assert_eq!(vec![1, 3], graph.path("soil", "light")?);
+        assert_eq!(vec![2, 4], graph.path("seed", "location")?);
+        assert_eq!(Vec::<usize>::new(), graph.path("water", "water")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_link() -> miette::Result<()> {
+        let graph = CategoryGraph::new([("seed", "soil"), ("water", "light")])?;
+
+        let Err(AocError::MissingLink { reachable, .. }) = graph.path("seed", "light") else {
+            panic!("seed should not lead to light");
+        };
+        assert_eq!("`soil`", reachable);
+        assert!(matches!(
+            graph.path("seed", "sunlight"),
+            Err(AocError::UnknownCategory { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(matches!(
+            CategoryGraph::new([("seed", "soil"), ("soil", "water"), ("water", "seed")]),
+            Err(AocError::CategoryCycle { .. })
+        ));
+        assert!(matches!(
+            CategoryGraph::new([("seed", "seed")]),
+            Err(AocError::CategoryCycle { .. })
+        ));
+        assert!(matches!(
+            CategoryGraph::new([("seed", "soil"), ("seed", "soil")]),
+            Err(AocError::DuplicateCategory { .. })
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+};
+
+/// End of the domain of every map: one past `u64::MAX`, so ranges have
+/// `u128` bounds and can reach `u64::MAX` itself.
+pub const END: u128 = 1 << 64;
+
+/// Numbers in `src` move to `dst` onwards, keeping their order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub src: Range<u128>,
+    pub dst: u64,
+}
+
+impl Segment {
+    /// How far the numbers of this segment move.
+    pub fn offset(&self) -> i128 {
+        i128::from(self.dst) - self.src.start as i128
+    }
+
+    fn map(&self, n: u128) -> u128 {
+        u128::from(self.dst) + (n - self.src.start)
+    }
+
+    fn dst_range(&self) -> Range<u128> {
+        u128::from(self.dst)..self.map(self.src.end)
+    }
+}
+
+/// Increasing-by-parts map of every `u64`, as segments sorted by source.
+///
+/// Numbers outside of every line of a category map to themselves, so gaps
+/// between lines are kept as segments with an offset of zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap(Vec<Segment>);
+
+impl PiecewiseMap {
+    /// Builds the map of one category from its `(src, dst)` lines, which
+    /// have to lie within `0..END`.
+    ///
+    /// Where lines overlap the one starting first wins.
+    pub fn new(lines: impl IntoIterator<Item = (Range<u128>, u64)>) -> Self {
+        let mut lines = lines
+            .into_iter()
+            .filter(|(src, _)| !src.is_empty())
+            .collect::<Vec<_>>();
+        lines.sort_by_key(|(src, _)| src.start);
+
+        let mut segments = Vec::with_capacity(2 * lines.len() + 1);
+        let mut covered = 0;
+
+        for (src, dst) in lines {
+            if src.end <= covered {
+                continue;
+            }
+            if covered < src.start {
+                segments.push(Segment {
+                    src: covered..src.start,
+                    dst: covered as u64,
+                });
+            }
+            let start = src.start.max(covered);
+            segments.push(Segment {
+                src: start..src.end,
+                dst: dst + (start - src.start) as u64,
+            });
+            covered = src.end;
+        }
+
+        if covered < END {
+            segments.push(Segment {
+                src: covered..END,
+                dst: covered as u64,
+            });
+        }
+
+        Self(segments)
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.0
+    }
+
+    /// Index of the segment containing `n`.
+    fn find(&self, n: u128) -> usize {
+        self.0.partition_point(|segment| segment.src.end <= n)
+    }
+
+    /// Segments overlapping `range`, in order.
+    fn overlapping(&self, range: Range<u128>) -> impl Iterator<Item = &Segment> {
+        let first = if range.is_empty() {
+            self.0.len()
+        } else {
+            self.find(range.start)
+        };
+
+        self.0[first..]
+            .iter()
+            .take_while(move |segment| segment.src.start < range.end)
+    }
+
+    pub fn look_up(&self, n: u64) -> u64 {
+        let n = u128::from(n);
+        // segments cover every u64, and map them to u64s
+        self.0.get(self.find(n)).map_or(n, |segment| segment.map(n)) as u64
+    }
+
+    /// Smallest number anything in `range` maps to.
+    ///
+    /// Every segment keeps order, so only the first number of each segment
+    /// overlapping `range` is a candidate.
+    pub fn min(&self, range: Range<u128>) -> Option<u64> {
+        let start = range.start;
+        self.overlapping(range)
+            .map(|segment| segment.map(segment.src.start.max(start)) as u64)
+            .min()
+    }
+
+    /// Map of `next` applied after `self`.
+    pub fn then(&self, next: &Self) -> Self {
+        let mut segments: Vec<Segment> = Vec::with_capacity(self.0.len() + next.0.len());
+
+        for segment in &self.0 {
+            let dst = segment.dst_range();
+
+            for after in next.overlapping(dst.clone()) {
+                let start = dst.start.max(after.src.start);
+                let end = dst.end.min(after.src.end);
+                let src_start = segment.src.start + (start - dst.start);
+                let piece = Segment {
+                    src: src_start..src_start + (end - start),
+                    dst: after.map(start) as u64,
+                };
+
+                match segments.last_mut() {
+                    Some(last)
+                        if last.src.end == piece.src.start
+                            && last.map(last.src.end) == u128::from(piece.dst) =>
+                    {
+                        last.src.end = piece.src.end;
+                    }
+                    _ => segments.push(piece),
+                }
+            }
+        }
+
+        Self(segments)
+    }
+}
+
+impl Display for PiecewiseMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.0 {
+            let Segment { src, .. } = segment;
+            writeln!(f, "{}..{} {:+}", src.start, src.end, segment.offset())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seed_to_soil() -> PiecewiseMap {
+        PiecewiseMap::new([(98..100, 50), (50..98, 52)])
+    }
+
+    #[test]
+    fn test_new() {
+        let segments = vec![
+            Segment { src: 0..50, dst: 0 },
+            Segment {
+                src: 50..98,
+                dst: 52,
+            },
+            Segment {
+                src: 98..100,
+                dst: 50,
+            },
+            Segment {
+                src: 100..END,
+                dst: 100,
+            },
+        ];
+
+        assert_eq!(segments, seed_to_soil().segments());
+    }
+
+    #[test]
+    fn test_look_up() {
+        let map = seed_to_soil();
+
+        assert_eq!(10, map.look_up(10));
+        assert_eq!(51, map.look_up(99));
+        assert_eq!(81, map.look_up(79));
+        assert_eq!(100, map.look_up(100));
+        assert_eq!(u64::MAX, map.look_up(u64::MAX));
+    }
+
+    #[test]
+    fn test_whole_u64_range() {
+        let end = PiecewiseMap::new([(u128::from(u64::MAX)..END, 7), (0..1, u64::MAX)]);
+
+        assert_eq!(7, end.look_up(u64::MAX));
+        assert_eq!(u64::MAX, end.look_up(0));
+        assert_eq!(Some(7), end.min(u128::from(u64::MAX - 1)..END));
+        assert_eq!(Some(u64::MAX), end.min(0..1));
+
+        // composing keeps the last number too
+        let twice = end.then(&end);
+        assert_eq!(7, twice.look_up(0));
+        assert_eq!(7, twice.look_up(7));
+        assert_eq!(7, twice.look_up(u64::MAX));
+        assert_eq!(u64::MAX - 1, twice.look_up(u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_min() {
+        let map = seed_to_soil();
+
+        assert_eq!(Some(50), map.min(60..100));
+        assert_eq!(Some(62), map.min(60..98));
+        assert_eq!(None, map.min(60..60));
+    }
+
+    #[test]
+    fn test_then() {
+        let map = seed_to_soil();
+        let soil_to_fertilizer = PiecewiseMap::new([(15..52, 0), (52..54, 37), (0..15, 39)]);
+        let composed = map.then(&soil_to_fertilizer);
+
+        for n in 0..200 {
+            assert_eq!(
+                soil_to_fertilizer.look_up(map.look_up(n)),
+                composed.look_up(n),
+                "{n}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let map = PiecewiseMap::new([(10..20, 0)]);
+
+        assert_eq!(
+            "0..10 +0\n10..20 -10\n20..18446744073709551616 +0\n",
+            map.to_string()
+        );
+    }
+}
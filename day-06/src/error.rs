@@ -1,13 +1,45 @@
-use miette::Diagnostic;
+use std::sync::Arc;
+
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
+    #[diagnostic(transparent)]
+    Core(#[from] aoc_core::Error),
+
+    #[error("`{row}` number does not fit in {ty}")]
+    #[diagnostic(code(day_06::overflow))]
+    Overflow {
+        row: String,
+        ty: &'static str,
+        #[source_code]
+        src: Arc<str>,
+        #[label("{digits} digits")]
+        span: SourceSpan,
+        digits: usize,
+    },
+
+    #[error("sheet has no `{label}` row")]
+    #[diagnostic(code(day_06::missing_row), help("the sheet has {rows}"))]
+    MissingRow { label: &'static str, rows: String },
 
-    #[error("cannot parse Sheet")]
-    #[diagnostic(code(aoc::parse_sheet_error))]
-    ParseSheetError,
+    #[error("`{label}` row lists {len} races but `{other}` row {other_len}")]
+    #[diagnostic(
+        code(day_06::ragged_rows),
+        help("every race needs both a time and a distance")
+    )]
+    RaggedRows {
+        label: String,
+        other: String,
+        len: usize,
+        other_len: usize,
+        #[source_code]
+        src: Arc<str>,
+        #[label("{len} races")]
+        span: SourceSpan,
+        #[label("{other_len} races")]
+        other_span: SourceSpan,
+    },
 }
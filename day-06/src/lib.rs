@@ -1,4 +1,31 @@
+use aoc_core::{Answer, Solution};
+
+use crate::{error::AocError, sheet::RaceSheet};
+
 pub mod error;
 
 pub mod part1;
 pub mod part2;
+pub mod race;
+pub mod sheet;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = RaceSheet<'a>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        RaceSheet::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,74 +1,33 @@
-use crate::error::AocError;
+use aoc_core::Answer;
 
-mod parsing {
-    use super::*;
-
-    use nom::{
-        bytes::complete::tag,
-        character::complete::{digit1, line_ending, multispace1},
-        combinator::map_res,
-        multi::separated_list1,
-        sequence::{preceded, terminated, tuple},
-        IResult,
-    };
-
-    fn numbers(input: &str) -> IResult<&str, Vec<&str>> {
-        preceded(multispace1, separated_list1(multispace1, digit1))(input)
-    }
-
-    pub fn sheet(input: &str) -> IResult<&str, (u64, u64)> {
-        let times = preceded(tag("Time:"), numbers);
-        let distances = preceded(tag("Distance:"), numbers);
-
-        let parse = tuple((terminated(times, line_ending), distances));
-        map_res(parse, |(times, distances)| {
-            let time = times.join("").parse::<u64>().unwrap();
-            let dist = distances.join("").parse::<u64>().unwrap();
-            Ok::<(u64, u64), &str>((time, dist))
-        })(input)
-    }
-
-    pub fn parse_sheet(input: &str) -> Result<(u64, u64), AocError> {
-        sheet(input)
-            .map(|x| x.1)
-            .map_err(|_| AocError::ParseSheetError)
-    }
-}
+use crate::{error::AocError, sheet::RaceSheet};
 
 #[tracing::instrument(skip(input))]
-pub fn process(input: &str) -> miette::Result<usize, AocError> {
-    let (time, dist) = parsing::parse_sheet(input)?;
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
+    solve(&RaceSheet::parse(input)?)
+}
 
-    let count = (1..time)
-        .filter(|speed| (time - speed) * speed > dist)
-        .count();
+/// Each row of the sheet is one number, spaces and all.
+#[tracing::instrument(skip(sheet))]
+pub fn solve(sheet: &RaceSheet) -> miette::Result<Answer, AocError> {
+    let race = sheet.kerned_race::<u64>()?;
 
-    Ok(count)
+    Ok(race.ways_to_win().into())
 }
 
 #[cfg(test)]
 mod tests {
-    use miette::IntoDiagnostic;
+    use aoc_core::{examples::Example, Part, Solution};
 
     use super::*;
-
-    const INPUT: &str = "Time:      7  15   30
-Distance:  9  40  200";
+    use crate::Day06;
 
     #[test_log::test]
-    fn test_parse_sheet() -> miette::Result<()> {
-        let parsed = parsing::parse_sheet(INPUT).into_diagnostic()?;
-        let expected = (71530, 940200);
+    fn test_process() -> miette::Result<()> {
+        let example = Example::load(Day06::DAY, Part::Two)?;
 
-        assert_eq!(parsed, expected);
+        assert_eq!(example.answer, Some(process(&example.input)?));
 
         Ok(())
     }
-
-    #[test_log::test]
-    fn test_process() -> miette::Result<()> {
-        let input = INPUT;
-        assert_eq!(71503, process(input)?);
-        Ok(())
-    }
 }
@@ -0,0 +1,179 @@
+//! Counting the ways to beat a race record in closed form.
+//!
+//! Holding the button for `h` of `t` milliseconds covers `h * (t - h)`
+//! millimeters, so the winning hold times are the integers strictly between
+//! the roots of `h² - t·h + d = 0`, `(t ± √(t² - 4d)) / 2`. Those are found
+//! with an integer square root and nudged onto the exact boundary, so there
+//! is no float rounding to get wrong for large races. Races too long for `t²`
+//! to fit in `u128` search for the boundary instead.
+
+use std::str::FromStr;
+
+/// Integer types races can be measured in.
+pub trait RaceInt: Copy + Into<u128> + TryFrom<u128> + FromStr {}
+
+impl RaceInt for u64 {}
+impl RaceInt for u128 {}
+
+/// One column of a race sheet, or all of them read as a single race.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race<T> {
+    pub time: T,
+    pub record: T,
+}
+
+impl<T: RaceInt> Race<T> {
+    /// See [`ways_to_win`].
+    pub fn ways_to_win(&self) -> T {
+        ways_to_win(self.time, self.record)
+    }
+}
+
+/// Number of hold times beating `record` in a race of `time`.
+///
+/// Works in `u128` whatever `T` is, for any race `T` can hold.
+pub fn ways_to_win<T: RaceInt>(time: T, record: T) -> T {
+    let count = ways_to_win_u128(time.into(), record.into());
+    // there are never more ways to win than hold times
+    T::try_from(count).unwrap_or_else(|_| unreachable!("{count} ways to win overflow"))
+}
+
+fn ways_to_win_u128(t: u128, d: u128) -> u128 {
+    // a distance too large for u128 beats any record
+    let wins = |h: u128| h.checked_mul(t - h).is_none_or(|distance| distance > d);
+    if !wins(t / 2) {
+        // even the best hold time falls short
+        return 0;
+    }
+
+    // first winning hold time is the smallest h > (t - √D) / 2
+    let first = match t.checked_mul(t).map(|square| square - 4 * d) {
+        Some(discriminant) => {
+            // the floor of the square root leaves it at most one off either way
+            let mut first = (t - discriminant.isqrt()) / 2;
+            while first > 0 && wins(first - 1) {
+                first -= 1;
+            }
+            while !wins(first) {
+                first += 1;
+            }
+            first
+        }
+        // distances only grow up to t / 2, so search for where they first win
+        None => {
+            let (mut lo, mut hi) = (0, t / 2);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if wins(mid) {
+                    hi = mid;
+                } else {
+                    lo = mid + 1;
+                }
+            }
+            lo
+        }
+    };
+
+    // winning hold times are symmetric around t / 2
+    t - 2 * first + 1
+}
+
+/// Reference for [`ways_to_win`] trying every hold time, linear in `time`.
+pub fn ways_to_win_scan(time: u64, record: u64) -> u64 {
+    let (time, record) = (u128::from(time), u128::from(record));
+    (1..time)
+        .filter(|hold| hold * (time - hold) > record)
+        .count() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(7, 9, 4)]
+    #[case(15, 40, 8)]
+    #[case(30, 200, 9)]
+    #[case(71530, 940200, 71503)]
+    #[case(0, 0, 0)]
+    #[case(1, 0, 0)]
+    #[case(2, 0, 1)]
+    #[case(2, 1, 0)]
+    // 5 * 5 = 25 ties the record and does not count
+    #[case(10, 25, 0)]
+    #[case(10, 24, 1)]
+    fn test_ways_to_win(#[case] time: u64, #[case] record: u64, #[case] expected: u64) {
+        assert_eq!(expected, ways_to_win(time, record));
+        assert_eq!(expected, ways_to_win_scan(time, record));
+    }
+
+    #[test]
+    fn test_matches_scan() {
+        let mut rng = StdRng::seed_from_u64(6);
+
+        for _ in 0..10_000 {
+            let time = rng.gen_range(0..2_000);
+            let best = (time / 2) * (time - time / 2);
+            // records around the best distance hit the boundary cases
+            let record = rng.gen_range(0..=best + 2);
+
+            assert_eq!(
+                ways_to_win_scan(time, record),
+                ways_to_win(time, record),
+                "time {time}, record {record}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_large_races() {
+        // a square record has a double root: only h = t / 2 ties it
+        let t = u64::MAX - 1;
+        let half = u128::from(t / 2);
+        assert_eq!(0, ways_to_win(u128::from(t), half * half));
+        assert_eq!(1, ways_to_win(u128::from(t), half * half - 1));
+
+        // too long to scan, so check the first winning hold time is exact
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..10_000 {
+            let time = rng.gen::<u64>();
+            let record = rng.gen_range(0..(u128::from(time) / 2).pow(2));
+            let count = ways_to_win(u128::from(time), record);
+
+            let (t, first) = (u128::from(time), (u128::from(time) + 1 - count) / 2);
+            assert!(first * (t - first) > record, "time {time}, record {record}");
+            assert!(
+                (first - 1) * (t - first + 1) <= record,
+                "time {time}, record {record}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_races_past_u64() {
+        // h = 5 covers 5 * 2^100 - 25, one short of the record; h = 6 wins
+        let t = 1u128 << 100;
+        assert_eq!(t - 11, ways_to_win(t, 5 * t - 1));
+        assert_eq!(u128::MAX - 1, ways_to_win(u128::MAX, 0));
+        // h = 1 ties the record, h = 2 covers more than u128 holds
+        assert_eq!(u128::MAX - 3, ways_to_win(u128::MAX, u128::MAX - 1));
+        assert_eq!(1, ways_to_win(2, 0u128));
+
+        // a distance too large for u128 beats any record
+        let wins = |t: u128, h: u128, d: u128| h.checked_mul(t - h).is_none_or(|dist| dist > d);
+        let mut rng = StdRng::seed_from_u64(6);
+        for _ in 0..10_000 {
+            // t² overflows from 2^64 on, so these all take the search
+            let t = rng.gen_range(u128::from(u64::MAX) + 1..=u128::MAX);
+            let d = rng.gen::<u128>() >> rng.gen_range(0..128);
+            let count = ways_to_win(t, d);
+
+            let first = (t + 1 - count) / 2;
+            assert!(wins(t, first, d), "time {t}, record {d}");
+            assert!(!wins(t, first - 1, d), "time {t}, record {d}");
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! The race sheet, kept as the digit groups it is written in.
+//!
+//! Part 1 reads every column as a race of its own; part 2 ignores the
+//! spaces ("bad kerning") and reads each row as one number.
+
+use std::{any::type_name, sync::Arc};
+
+use itertools::Itertools;
+use miette::SourceSpan;
+
+use crate::{
+    error::AocError,
+    race::{Race, RaceInt},
+};
+
+/// Label of the row holding race times.
+pub const TIME: &str = "Time";
+
+/// Label of the row holding record distances.
+pub const DISTANCE: &str = "Distance";
+
+/// Digits as written on the sheet, not yet read as a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigitGroup<'a> {
+    pub digits: &'a str,
+    pub span: SourceSpan,
+}
+
+/// `Label:` followed by any number of digit groups.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row<'a> {
+    pub label: &'a str,
+    pub span: SourceSpan,
+    pub groups: Vec<DigitGroup<'a>>,
+}
+
+/// Rows of a race sheet, in order; rows other than [`TIME`] and
+/// [`DISTANCE`] are kept but take no part in either reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaceSheet<'a> {
+    input: &'a str,
+    rows: Vec<Row<'a>>,
+}
+
+impl<'a> RaceSheet<'a> {
+    pub fn parse(input: &'a str) -> Result<Self, AocError> {
+        let rows = parsing::parse_rows(input)?;
+        Ok(Self { input, rows })
+    }
+
+    pub fn rows(&self) -> &[Row<'a>] {
+        &self.rows
+    }
+
+    pub fn row(&self, label: &'static str) -> Result<&Row<'a>, AocError> {
+        self.rows
+            .iter()
+            .find(|row| row.label == label)
+            .ok_or_else(|| AocError::MissingRow {
+                label,
+                rows: self
+                    .rows
+                    .iter()
+                    .map(|row| format!("`{}`", row.label))
+                    .join(", "),
+            })
+    }
+
+    /// Every column is a race of its own.
+    pub fn races<T: RaceInt>(&self) -> Result<Vec<Race<T>>, AocError> {
+        let (times, distances) = (self.row(TIME)?, self.row(DISTANCE)?);
+        if times.groups.len() != distances.groups.len() {
+            return Err(AocError::RaggedRows {
+                label: times.label.to_string(),
+                other: distances.label.to_string(),
+                len: times.groups.len(),
+                other_len: distances.groups.len(),
+                src: self.src(),
+                span: times.span,
+                other_span: distances.span,
+            });
+        }
+
+        let times = times.groups.iter().map(|group| self.read(times, group));
+        let records = distances
+            .groups
+            .iter()
+            .map(|group| self.read(distances, group));
+        times
+            .zip(records)
+            .map(|(time, record)| {
+                Ok(Race {
+                    time: time?,
+                    record: record?,
+                })
+            })
+            .collect()
+    }
+
+    /// Digits of each row joined together, spaces ignored, as one race.
+    pub fn kerned_race<T: RaceInt>(&self) -> Result<Race<T>, AocError> {
+        Ok(Race {
+            time: self.read_kerned(self.row(TIME)?)?,
+            record: self.read_kerned(self.row(DISTANCE)?)?,
+        })
+    }
+
+    fn read<T: RaceInt>(&self, row: &Row, group: &DigitGroup) -> Result<T, AocError> {
+        self.number(row, group.digits, group.span)
+    }
+
+    fn read_kerned<T: RaceInt>(&self, row: &Row) -> Result<T, AocError> {
+        let digits = row.groups.iter().map(|group| group.digits).join("");
+        let span = match (row.groups.first(), row.groups.last()) {
+            (Some(first), Some(last)) => {
+                (first.span.offset()..last.span.offset() + last.span.len()).into()
+            }
+            _ => row.span,
+        };
+        self.number(row, &digits, span)
+    }
+
+    /// Reads digits, which can only fail by not fitting in `T`.
+    fn number<T: RaceInt>(&self, row: &Row, digits: &str, span: SourceSpan) -> Result<T, AocError> {
+        digits.parse().map_err(|_| AocError::Overflow {
+            row: row.label.to_string(),
+            ty: type_name::<T>(),
+            src: self.src(),
+            span,
+            digits: digits.len(),
+        })
+    }
+
+    fn src(&self) -> Arc<str> {
+        self.input.into()
+    }
+}
+
+mod parsing {
+    use super::*;
+
+    use aoc_core::parsing::{parse, IResult, Span};
+    use nom::{
+        character::complete::{alpha1, digit1, line_ending, multispace0, space1},
+        combinator::{consumed, cut, map},
+        multi::{many1, separated_list1},
+        sequence::{preceded, terminated, tuple},
+        Parser,
+    };
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    fn source_span(span: Span) -> SourceSpan {
+        (span.location_offset(), span.fragment().len()).into()
+    }
+
+    fn row(input: Span) -> IResult<Row> {
+        let label = terminated(alpha1, tag(":")).context("label");
+        let group = map(digit1, |digits: Span| DigitGroup {
+            digits: digits.fragment(),
+            span: source_span(digits),
+        });
+        let groups = many1(preceded(space1, group)).context("numbers");
+
+        map(
+            consumed(tuple((label, cut(groups)))),
+            |(span, (label, groups)): (Span, (Span, _))| Row {
+                label: label.fragment(),
+                span: source_span(span),
+                groups,
+            },
+        )
+        .context("row")
+        .parse(input)
+    }
+
+    pub fn parse_rows(input: &str) -> Result<Vec<Row<'_>>, AocError> {
+        let rows = terminated(separated_list1(line_ending, row), multispace0);
+        Ok(parse("sheet", input, rows)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+
+    use super::*;
+    use crate::Day06;
+
+    #[test_log::test]
+    fn test_readings() -> miette::Result<()> {
+        let example = Example::load(Day06::DAY, Part::One)?;
+        let sheet = RaceSheet::parse(&example.input)?;
+
+        let races = [(7, 9), (15, 40), (30, 200)]
+            .map(|(time, record)| Race { time, record })
+            .to_vec();
+        assert_eq!(races, sheet.races::<u64>()?);
+        assert_eq!(
+            Race {
+                time: 71530,
+                record: 940200
+            },
+            sheet.kerned_race::<u64>()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_any_number_of_rows_and_columns() -> miette::Result<()> {
+        let input = "Time: 1 2 3 4 5\nLane: 3 1\nDistance: 0 0 0 0 9\n";
+        let sheet = RaceSheet::parse(input)?;
+
+        assert_eq!(3, sheet.rows().len());
+        assert_eq!(5, sheet.races::<u64>()?.len());
+        assert_eq!(12345, sheet.kerned_race::<u128>()?.time);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kerned_overflow() -> miette::Result<()> {
+        let input = "Time: 1844674407 3709551616\nDistance: 1 2\n";
+        let sheet = RaceSheet::parse(input)?;
+
+        let Err(AocError::Overflow { row, ty, span, .. }) = sheet.kerned_race::<u64>() else {
+            panic!("u64::MAX + 1 should not fit in u64");
+        };
+        assert_eq!((TIME, "u64"), (row.as_str(), ty));
+        assert_eq!(input.find("18").unwrap(), span.offset());
+        assert_eq!("1844674407 3709551616".len(), span.len());
+
+        assert_eq!(18446744073709551616, sheet.kerned_race::<u128>()?.time);
+        assert!(sheet.races::<u64>().is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed_sheets() -> miette::Result<()> {
+        let sheet = RaceSheet::parse("Time: 7 15\nDistance: 9\n")?;
+        assert!(matches!(
+            sheet.races::<u64>(),
+            Err(AocError::RaggedRows {
+                len: 2,
+                other_len: 1,
+                ..
+            })
+        ));
+
+        let sheet = RaceSheet::parse("Time: 7 15\n")?;
+        assert!(matches!(
+            sheet.kerned_race::<u64>(),
+            Err(AocError::MissingRow {
+                label: DISTANCE,
+                ..
+            })
+        ));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use aoc_core::{Part, Solution};
 use day_07::*;
 
 fn main() {
@@ -5,18 +6,38 @@ fn main() {
     divan::main();
 }
 
+/// Reads the input up front, set `AOC_INPUT` to bench against another file.
+fn input(part: Part) -> String {
+    Day07::input(part).unwrap()
+}
+
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = input(Part::One);
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = input(Part::Two);
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
+}
+
+/// Ranking hands by packed key against comparing them field by field.
+mod ranking {
+    use day_07::camel::{parse_hands, total_winnings, total_winnings_compared, Jokers, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [Standard, Jokers])]
+    fn packed_keys<R: camel::Ruleset + Default>(bencher: divan::Bencher) {
+        let hands = parse_hands(&input(Part::One)).unwrap();
+        bencher.bench(|| total_winnings(divan::black_box(&hands), &R::default()));
+    }
+
+    #[divan::bench(types = [Standard, Jokers])]
+    fn comparator<R: camel::Ruleset + Default>(bencher: divan::Bencher) {
+        let hands = parse_hands(&input(Part::One)).unwrap();
+        bencher.bench(|| total_winnings_compared(divan::black_box(&hands), &R::default()));
+    }
 }
@@ -0,0 +1,375 @@
+//! Camel Cards: hands of five cards, each with a bid, ranked against each
+//! other to work out the winnings.
+//!
+//! How cards compare and what type a hand is depends on the [`Ruleset`];
+//! part 1 plays by [`Standard`] rules, part 2 with [`Jokers`].
+
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use thiserror::Error;
+
+pub use self::parsing::{parse_hands, parse_hands_partial};
+use crate::error::AocError;
+
+/// Cards by face value, from `2` up to `A`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl TryFrom<char> for Card {
+    type Error = NotACard;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '2' => Card::Two,
+            '3' => Card::Three,
+            '4' => Card::Four,
+            '5' => Card::Five,
+            '6' => Card::Six,
+            '7' => Card::Seven,
+            '8' => Card::Eight,
+            '9' => Card::Nine,
+            'T' => Card::T,
+            'J' => Card::J,
+            'Q' => Card::Q,
+            'K' => Card::K,
+            'A' => Card::A,
+            c => return Err(NotACard(c)),
+        })
+    }
+}
+
+/// A character where a card should be; shows as what the parser expected.
+#[derive(Error, Debug)]
+#[error("a card (2-9, T, J, Q, K, A)")]
+pub struct NotACard(pub char);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub bid: u32,
+}
+
+impl Hand {
+    pub fn new(cards: Vec<Card>, bid: u32) -> Self {
+        Self { cards, bid }
+    }
+}
+
+/// Hand types from weakest to strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Type of a hand holding `counts` of its distinct cards, largest first.
+    fn from_counts(counts: &[usize]) -> Self {
+        match counts {
+            [5] => HandType::FiveOfAKind,
+            [4, ..] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, ..] => HandType::ThreeOfAKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// How cards rank against each other and what type a hand is.
+pub trait Ruleset {
+    /// Strength of a card when breaking ties between hands of a type, below
+    /// 16 so it packs into [`Ruleset::key`].
+    fn strength(&self, card: Card) -> u8;
+
+    fn hand_type(&self, cards: &[Card]) -> HandType;
+
+    /// Orders hands by type first, then card by card in the order dealt.
+    fn compare(&self, a: &Hand, b: &Hand) -> Ordering {
+        self.hand_type(&a.cards)
+            .cmp(&self.hand_type(&b.cards))
+            .then_with(|| {
+                let strength = |&card: &Card| self.strength(card);
+                a.cards
+                    .iter()
+                    .map(strength)
+                    .cmp(b.cards.iter().map(strength))
+            })
+    }
+
+    /// Packs [`Ruleset::compare`] into one integer: the type in the high
+    /// bits, then 4 bits per card, first card highest.
+    fn key(&self, cards: &[Card]) -> u32 {
+        cards
+            .iter()
+            .fold(self.hand_type(cards) as u32, |key, &card| {
+                key << 4 | u32::from(self.strength(card))
+            })
+    }
+}
+
+/// Cards rank by face value and the type counts matching cards.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Standard;
+
+impl Ruleset for Standard {
+    fn strength(&self, card: Card) -> u8 {
+        card as u8
+    }
+
+    fn hand_type(&self, cards: &[Card]) -> HandType {
+        let counts = cards.iter().counts().into_values().sorted().rev();
+        HandType::from_counts(&counts.collect_vec())
+    }
+}
+
+/// `J` is a joker: the weakest card when breaking ties, but it stands in for
+/// whichever card makes the strongest type.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Jokers;
+
+impl Ruleset for Jokers {
+    fn strength(&self, card: Card) -> u8 {
+        match card {
+            Card::J => 0,
+            card => card as u8 + 1,
+        }
+    }
+
+    fn hand_type(&self, cards: &[Card]) -> HandType {
+        let jokers = cards.iter().filter(|&&card| card == Card::J).count();
+        let mut counts = (cards.iter().filter(|&&card| card != Card::J))
+            .counts()
+            .into_values()
+            .sorted()
+            .rev()
+            .collect_vec();
+        // jokers always do best joining the largest group
+        match counts.first_mut() {
+            Some(largest) => *largest += jokers,
+            None => counts.push(jokers),
+        }
+        HandType::from_counts(&counts)
+    }
+}
+
+/// Sum of every bid times the rank of its hand, weakest hand ranking 1.
+///
+/// Ranks hands by their [`Ruleset::key`], so sorting is one integer sort.
+pub fn total_winnings(hands: &[Hand], rules: &impl Ruleset) -> u64 {
+    let mut keyed = hands
+        .iter()
+        .map(|hand| (rules.key(&hand.cards), hand.bid))
+        .collect_vec();
+    keyed.sort_by_key(|&(key, _)| key);
+
+    winnings(keyed.into_iter().map(|(_, bid)| bid))
+}
+
+/// [`total_winnings`] sorting hands with [`Ruleset::compare`] instead, kept
+/// to check and benchmark the packed keys against.
+pub fn total_winnings_compared(hands: &[Hand], rules: &impl Ruleset) -> u64 {
+    let mut hands = hands.iter().collect_vec();
+    hands.sort_by(|a, b| rules.compare(a, b));
+
+    winnings(hands.iter().map(|hand| hand.bid))
+}
+
+/// Winnings of bids listed from the weakest hand up.
+fn winnings(bids: impl Iterator<Item = u32>) -> u64 {
+    bids.zip(1..).map(|(bid, rank)| rank * u64::from(bid)).sum()
+}
+
+mod parsing {
+    use super::*;
+
+    use aoc_core::parsing::{parse_lines_partial, IResult, Partial, Span};
+    use nom::{
+        character::complete::{anychar, space1, u32},
+        combinator::{cut, map, map_res},
+        multi::count,
+        sequence::separated_pair,
+    };
+    use nom_supreme::ParserExt;
+
+    pub fn cards(input: Span) -> IResult<Vec<Card>> {
+        // T55J5 684
+        count(map_res(anychar, Card::try_from).context("card"), 5)(input)
+    }
+
+    pub fn hand(input: Span) -> IResult<Hand> {
+        map(
+            separated_pair(cards, space1, cut(u32.context("bid"))),
+            |(cards, bid)| Hand::new(cards, bid),
+        )(input)
+    }
+
+    pub fn parse_hands(input: &str) -> Result<Vec<Hand>, AocError> {
+        Ok(parse_hands_partial(input).into_result()?)
+    }
+
+    /// Every hand that parses, next to the errors of those that don't, to
+    /// look at what got through when an input is malformed.
+    pub fn parse_hands_partial(input: &str) -> Partial<Hand> {
+        parse_lines_partial("hand", input, hand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+    use miette::IntoDiagnostic;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Day07;
+
+    fn hand(cards: &str) -> Hand {
+        parse_hands(&format!("{cards} 1")).unwrap().remove(0)
+    }
+
+    #[test_log::test]
+    fn test_parse_hands() -> miette::Result<()> {
+        let input = "T55J5 684\nKK677 28";
+        let parsed = parse_hands(input).into_diagnostic()?;
+        let expected = vec![
+            Hand::new(
+                vec![Card::T, Card::Five, Card::Five, Card::J, Card::Five],
+                684,
+            ),
+            Hand::new(
+                vec![Card::K, Card::K, Card::Six, Card::Seven, Card::Seven],
+                28,
+            ),
+        ];
+
+        assert_eq!(expected, parsed);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_hands_partial() {
+        let partial = parse_hands_partial("32T3K 765\nT55J5\nKK677 28\nKTJJ 220\n");
+
+        assert_eq!(
+            vec![765, 28],
+            partial.parsed.iter().map(|hand| hand.bid).collect_vec()
+        );
+        assert_eq!(2, partial.errors.map_or(0, |errors| errors.errors.len()));
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "32T3K 765\nT55X5 684";
+        let Err(AocError::Core(aoc_core::Error::Parse(err))) = parse_hands(input) else {
+            panic!("X is not a card");
+        };
+
+        assert_eq!(input.find('X'), Some(err.span.offset()));
+        assert_eq!("a card (2-9, T, J, Q, K, A)", err.expected);
+    }
+
+    #[rstest]
+    #[case("AAAAA", HandType::FiveOfAKind, HandType::FiveOfAKind)]
+    #[case("AA8AA", HandType::FourOfAKind, HandType::FourOfAKind)]
+    #[case("23332", HandType::FullHouse, HandType::FullHouse)]
+    #[case("TTT98", HandType::ThreeOfAKind, HandType::ThreeOfAKind)]
+    #[case("23432", HandType::TwoPair, HandType::TwoPair)]
+    #[case("A23A4", HandType::OnePair, HandType::OnePair)]
+    #[case("23456", HandType::HighCard, HandType::HighCard)]
+    #[case("T55J5", HandType::ThreeOfAKind, HandType::FourOfAKind)]
+    #[case("KTJJT", HandType::TwoPair, HandType::FourOfAKind)]
+    #[case("2345J", HandType::HighCard, HandType::OnePair)]
+    #[case("2233J", HandType::TwoPair, HandType::FullHouse)]
+    #[case("JJJJJ", HandType::FiveOfAKind, HandType::FiveOfAKind)]
+    fn test_hand_type(#[case] cards: &str, #[case] standard: HandType, #[case] jokers: HandType) {
+        let hand = hand(cards);
+        assert_eq!(standard, Standard.hand_type(&hand.cards));
+        assert_eq!(jokers, Jokers.hand_type(&hand.cards));
+    }
+
+    #[test]
+    fn test_ordering() {
+        // same type, first differing card decides
+        assert_eq!(
+            Ordering::Greater,
+            Standard.compare(&hand("33332"), &hand("2AAAA"))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Standard.compare(&hand("KK677"), &hand("KTJJT"))
+        );
+
+        // a joker is weaker than a 2 when breaking ties
+        assert_eq!(
+            Ordering::Less,
+            Jokers.compare(&hand("JKKK2"), &hand("QQQQ2"))
+        );
+        assert_eq!(
+            Ordering::Less,
+            Jokers.compare(&hand("J2222"), &hand("22222"))
+        );
+
+        // but makes the better type where it is wild
+        assert_eq!(
+            Ordering::Less,
+            Standard.compare(&hand("JKKK2"), &hand("QKKK2"))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            Jokers.compare(&hand("JKKK2"), &hand("QKKK2"))
+        );
+    }
+
+    #[test]
+    fn test_keys_agree_with_compare() -> miette::Result<()> {
+        let mut hands = parse_hands(&Example::load(Day07::DAY, Part::One)?.input)?;
+        hands.extend(
+            [
+                "JKKK2", "QKKK2", "J2222", "22222", "JJJJJ", "AAAAA", "2345J", "AKQT9", "2233J",
+            ]
+            .map(hand),
+        );
+
+        for (a, b) in hands.iter().tuple_combinations() {
+            assert_eq!(
+                Standard.compare(a, b),
+                Standard.key(&a.cards).cmp(&Standard.key(&b.cards)),
+                "{a:?} {b:?}"
+            );
+            assert_eq!(
+                Jokers.compare(a, b),
+                Jokers.key(&a.cards).cmp(&Jokers.key(&b.cards)),
+                "{a:?} {b:?}"
+            );
+        }
+        assert_eq!(
+            total_winnings_compared(&hands, &Jokers),
+            total_winnings(&hands, &Jokers)
+        );
+
+        Ok(())
+    }
+}
@@ -4,10 +4,6 @@ use thiserror::Error;
 #[derive(Error, Diagnostic, Debug)]
 pub enum AocError {
     #[error(transparent)]
-    #[diagnostic(code(aoc::io_error))]
-    IoError(#[from] std::io::Error),
-
-    #[error("cannot parse hands")]
-    #[diagnostic(code(aoc::parse_hands_error))]
-    ParseHandsError,
+    #[diagnostic(transparent)]
+    Core(#[from] aoc_core::Error),
 }
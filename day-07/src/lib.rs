@@ -1,4 +1,33 @@
+use aoc_core::{Answer, Solution};
+
+use crate::{
+    camel::{parse_hands, Hand},
+    error::AocError,
+};
+
+pub mod camel;
 pub mod error;
 
 pub mod part1;
 pub mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Hand>;
+    type Error = AocError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_hands(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part2::solve(input)
+    }
+}
@@ -1,19 +1,33 @@
-use crate::error::AocError;
+use aoc_core::Answer;
+
+use crate::{
+    camel::{parse_hands, total_winnings, Hand, Jokers},
+    error::AocError,
+};
 
 #[tracing::instrument]
-pub fn process(_input: &str) -> miette::Result<String, AocError> {
-    todo!("day 01 - part 2");
+pub fn process(input: &str) -> miette::Result<Answer, AocError> {
+    solve(&parse_hands(input)?)
+}
+
+#[tracing::instrument(skip(hands))]
+pub fn solve(hands: &[Hand]) -> miette::Result<Answer, AocError> {
+    Ok(total_winnings(hands, &Jokers).into())
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::{examples::Example, Part, Solution};
+
     use super::*;
+    use crate::Day07;
 
-    #[test]
+    #[test_log::test]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
-        let input = "";
-        assert_eq!("", process(input)?);
+        let example = Example::load(Day07::DAY, Part::Two)?;
+
+        assert_eq!(example.answer, Some(process(&example.input)?));
+
         Ok(())
     }
 }
@@ -9,15 +9,19 @@
 //! nom = "7.1"
 //! reqwest = { version = "0.11", features = ["blocking"] }
 //! dotenvy = "0.15"
+//! miette = { version = "7.2", features = ["fancy"] }
 //! ```
 
 use clap::{error::ErrorKind, CommandFactory, Parser};
-use dotenvy::dotenv;
+use miette::{miette, IntoDiagnostic, Result, WrapErr};
 use nom::{bytes::complete::tag, character::complete, sequence::preceded, IResult};
 use reqwest::{blocking::Client, header::COOKIE};
-use std::fs::File;
-use std::io::Write;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Downloaded inputs and puzzle pages live here, keyed by year and day,
+/// e.g. `.cache/2023/day-05/input.txt` and `.cache/2023/day-05/puzzle.html`.
+const CACHE_DIR: &str = ".cache";
 
 #[derive(Parser, Debug)]
 struct Args {
@@ -25,6 +29,14 @@ struct Args {
     #[clap(short, long)]
     day: String,
 
+    /// Year of the event
+    #[clap(short, long, default_value_t = 2023)]
+    year: u16,
+
+    /// Download again even if already cached, e.g. to get part 2 of the puzzle page
+    #[clap(short, long)]
+    force: bool,
+
     /// For justfile
     #[clap(long("current-working-directory"))]
     cwd: PathBuf,
@@ -34,9 +46,71 @@ fn parse_day(input: &str) -> IResult<&str, u32> {
     preceded(tag("day-"), complete::u32)(input)
 }
 
-fn main() -> Result<(), reqwest::Error> {
-    dotenv().expect(".env file not found");
-    let session = std::env::var("SESSION").expect("SESSION environment variable is not set");
+/// Reads `SESSION` from the environment, falling back to `.env`.
+fn session() -> Result<String> {
+    if let Ok(session) = std::env::var("SESSION") {
+        return Ok(session);
+    }
+
+    match dotenvy::dotenv() {
+        Ok(_) => {}
+        Err(err) if err.not_found() => {
+            return Err(miette!(
+                help = "create `.env` with `SESSION=<cookie>`, see `justfile` for where to find the cookie",
+                "`SESSION` is not set and there is no `.env` file"
+            ))
+        }
+        Err(err) => return Err(err).into_diagnostic().wrap_err("cannot read `.env`"),
+    }
+
+    std::env::var("SESSION").map_err(|_| {
+        miette!(
+            help = "add `SESSION=<cookie>` to `.env`, see `justfile` for where to find the cookie",
+            "`.env` does not set `SESSION`"
+        )
+    })
+}
+
+fn download(url: &str) -> Result<String> {
+    let session = session()?;
+    println!("sending to `{}`", url);
+
+    Client::new()
+        .get(url)
+        .header(COOKIE, format!("session={session}"))
+        .send()
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .into_diagnostic()
+        .wrap_err_with(|| format!("cannot download `{url}`"))
+}
+
+fn write(path: &Path, data: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("cannot create {}", dir.display()))?;
+    }
+    fs::write(path, data)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("cannot write {}", path.display()))
+}
+
+/// Contents of `cached`, downloading `url` into it first if needed.
+fn cached(cached: &Path, url: &str, force: bool) -> Result<String> {
+    if cached.exists() && !force {
+        println!("using cached {}", cached.display());
+        return fs::read_to_string(cached)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("cannot read {}", cached.display()));
+    }
+
+    let data = download(url)?;
+    write(cached, &data)?;
+    Ok(data)
+}
+
+fn main() -> Result<()> {
     let args = Args::parse();
 
     let Ok((_, day)) = parse_day(&args.day) else {
@@ -48,23 +122,24 @@ fn main() -> Result<(), reqwest::Error> {
         .exit();
     };
 
-    let url = format!("https://adventofcode.com/2023/day/{day}/input");
-    println!("sending to `{}`", url);
+    let cache_dir = args
+        .cwd
+        .join(CACHE_DIR)
+        .join(args.year.to_string())
+        .join(format!("day-{day:02}"));
+    let url = format!("https://adventofcode.com/{}/day/{day}", args.year);
 
-    let client = Client::new();
-    let input_data = client
-        .get(url)
-        .header(COOKIE, format!("session={session}"))
-        .send()?
-        .text()?;
-
-    for filename in ["input1.txt", "input2.txt"] {
-        let file_path = args.cwd.join(&args.day).join(filename);
-        let mut file = File::create(&file_path).expect("unable to create file");
-        file.write_all(input_data.as_bytes())
-            .expect("unable to write input file");
-        println!("wrote {}", file_path.display());
-    }
+    // `aoc examples` turns the page into test fixtures
+    cached(&cache_dir.join("puzzle.html"), &url, args.force)?;
+    let input_data = cached(
+        &cache_dir.join("input.txt"),
+        &format!("{url}/input"),
+        args.force,
+    )?;
+
+    let file_path = args.cwd.join(&args.day).join("input.txt");
+    write(&file_path, &input_data)?;
+    println!("wrote {}", file_path.display());
 
     Ok(())
 }
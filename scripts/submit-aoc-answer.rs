@@ -0,0 +1,440 @@
+#!/usr/bin/env rust-script
+
+//! ```cargo
+//! [package]
+//! edition = "2021"
+//!
+//! [dependencies]
+//! clap = { version = "4.4", features = ["derive"] }
+//! nom = "7.1"
+//! reqwest = { version = "0.11", features = ["blocking"] }
+//! dotenvy = "0.15"
+//! miette = { version = "7.2", features = ["fancy"] }
+//! serde = { version = "1.0", features = ["derive"] }
+//! serde_json = "1.0"
+//!
+//! [dev-dependencies]
+//! tiny_http = "0.12"
+//! ```
+
+use clap::{error::ErrorKind, CommandFactory, Parser};
+use miette::{miette, IntoDiagnostic, Result, WrapErr};
+use nom::{bytes::complete::tag, character::complete, sequence::preceded, IResult};
+use reqwest::{blocking::Client, header::COOKIE};
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Shared with `get-aoc-input.rs`, attempts go to `.cache/2023/day-05/attempts.json`.
+const CACHE_DIR: &str = ".cache";
+
+const AOC_URL: &str = "https://adventofcode.com";
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// Expected to have format like "day-xx"
+    #[clap(short, long)]
+    day: String,
+
+    /// Part, `1` or `2`
+    #[clap(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The answer to submit
+    answer: String,
+
+    /// Year of the event
+    #[clap(short, long, default_value_t = 2023)]
+    year: u16,
+
+    /// For justfile
+    #[clap(long("current-working-directory"))]
+    cwd: PathBuf,
+}
+
+fn parse_day(input: &str) -> IResult<&str, u32> {
+    preceded(tag("day-"), complete::u32)(input)
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after a wrong answer, nothing was checked.
+    TooSoon,
+    /// The part is already solved or still locked.
+    WrongLevel,
+    /// A page we don't know how to read.
+    Unknown,
+}
+
+impl Outcome {
+    /// Reads the `<article>` of the page the site answers a submission with.
+    fn from_page(html: &str) -> Self {
+        let article = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(html, |(article, _)| article);
+
+        if article.contains("That's the right answer") {
+            Self::Correct
+        } else if article.contains("That's not the right answer") {
+            if article.contains("your answer is too high") {
+                Self::TooHigh
+            } else if article.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            }
+        } else if article.contains("You gave an answer too recently") {
+            Self::TooSoon
+        } else if article.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel
+        } else {
+            Self::Unknown
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Correct => "that's the right answer",
+            Self::TooHigh => "wrong, too high",
+            Self::TooLow => "wrong, too low",
+            Self::Wrong => "wrong",
+            Self::TooSoon => "answered too recently, try again later",
+            Self::WrongLevel => "this part is already solved or still locked",
+            Self::Unknown => "unrecognized response",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Attempt {
+    part: u8,
+    answer: String,
+    outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    at: u64,
+}
+
+/// Every answer submitted for one day, oldest first.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Attempts(Vec<Attempt>);
+
+impl Attempts {
+    fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("cannot parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("cannot read {}", path.display())),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("cannot create {}", dir.display()))?;
+        }
+        let mut json = serde_json::to_string_pretty(self).into_diagnostic()?;
+        json.push('\n');
+        fs::write(path, json)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("cannot write {}", path.display()))
+    }
+
+    fn push(&mut self, part: u8, answer: &str, outcome: Outcome) {
+        let at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.0.push(Attempt {
+            part,
+            answer: answer.to_string(),
+            outcome,
+            at,
+        });
+    }
+
+    /// Why `answer` is not worth sending, judging by earlier attempts.
+    fn reject(&self, part: u8, answer: &str) -> Option<String> {
+        let attempts = self.0.iter().filter(|attempt| attempt.part == part);
+
+        if let Some(correct) = attempts.clone().find(|a| a.outcome == Outcome::Correct) {
+            return Some(format!(
+                "part {part} is already solved with `{}`",
+                correct.answer
+            ));
+        }
+        if let Some(wrong) = attempts
+            .clone()
+            .find(|a| a.answer == answer && a.outcome.is_wrong())
+        {
+            return Some(format!("`{answer}` was already sent: {}", wrong.outcome));
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+        let bound = |outcome: Outcome| {
+            attempts
+                .clone()
+                .filter(move |a| a.outcome == outcome)
+                .filter_map(|a| a.answer.parse::<i128>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).min().filter(|&high| value >= high) {
+            return Some(format!(
+                "`{answer}` is not below `{high}`, which was too high"
+            ));
+        }
+        if let Some(low) = bound(Outcome::TooLow).max().filter(|&low| value <= low) {
+            return Some(format!(
+                "`{answer}` is not above `{low}`, which was too low"
+            ));
+        }
+
+        None
+    }
+}
+
+/// Reads `SESSION` from the environment, falling back to `.env`.
+fn session() -> Result<String> {
+    if let Ok(session) = std::env::var("SESSION") {
+        return Ok(session);
+    }
+
+    match dotenvy::dotenv() {
+        Ok(_) => {}
+        Err(err) if err.not_found() => {
+            return Err(miette!(
+                help = "create `.env` with `SESSION=<cookie>`, see `justfile` for where to find the cookie",
+                "`SESSION` is not set and there is no `.env` file"
+            ))
+        }
+        Err(err) => return Err(err).into_diagnostic().wrap_err("cannot read `.env`"),
+    }
+
+    std::env::var("SESSION").map_err(|_| {
+        miette!(
+            help = "add `SESSION=<cookie>` to `.env`, see `justfile` for where to find the cookie",
+            "`.env` does not set `SESSION`"
+        )
+    })
+}
+
+/// Posts `answer` to the site at `base_url` and reads the verdict.
+fn submit(
+    base_url: &str,
+    session: &str,
+    year: u16,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    let url = format!("{base_url}/{year}/day/{day}/answer");
+    println!("sending to `{}`", url);
+
+    let page = Client::new()
+        .post(&url)
+        .header(COOKIE, format!("session={session}"))
+        .form(&[("level", part.to_string().as_str()), ("answer", answer)])
+        .send()
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .into_diagnostic()
+        .wrap_err_with(|| format!("cannot submit to `{url}`"))?;
+
+    Ok(Outcome::from_page(&page))
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let Ok((_, day)) = parse_day(&args.day) else {
+        let mut cmd = Args::command();
+        cmd.error(
+            ErrorKind::ValueValidation,
+            format!("day `{}` must be formatted as `day-xx`", args.day),
+        )
+        .exit();
+    };
+
+    let answer = args.answer.trim();
+    let log = args
+        .cwd
+        .join(CACHE_DIR)
+        .join(args.year.to_string())
+        .join(format!("day-{day:02}"))
+        .join("attempts.json");
+    let mut attempts = Attempts::load(&log)?;
+
+    if let Some(reason) = attempts.reject(args.part, answer) {
+        return Err(miette!("not submitting: {reason}"));
+    }
+
+    let outcome = submit(AOC_URL, &session()?, args.year, day, args.part, answer)?;
+    attempts.push(args.part, answer, outcome.clone());
+    attempts.save(&log)?;
+
+    println!("{}", outcome);
+    if outcome == Outcome::Correct {
+        Ok(())
+    } else {
+        Err(miette!("`{answer}` was not accepted: {outcome}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    /// Serves `page` once, handing back the path, cookie and body it was sent.
+    fn stand_in(page: &'static str) -> (String, thread::JoinHandle<(String, String, String)>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+
+        let handle = thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string())
+                .unwrap_or_default();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let path = request.url().to_string();
+            request
+                .respond(tiny_http::Response::from_string(page))
+                .unwrap();
+            (path, cookie, body)
+        });
+
+        (base_url, handle)
+    }
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = stand_in(
+            "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        );
+
+        let outcome = submit(&base_url, "cookie", 2023, 5, 2, "46").unwrap();
+        let (path, cookie, body) = server.join().unwrap();
+
+        assert_eq!(Outcome::Correct, outcome);
+        assert_eq!("/2023/day/5/answer", path);
+        assert_eq!("session=cookie", cookie);
+        assert_eq!("level=2&answer=46", body);
+    }
+
+    #[test]
+    fn test_submit_too_high() {
+        let (base_url, server) = stand_in(
+            "<main><article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article></main>",
+        );
+
+        let outcome = submit(&base_url, "cookie", 2023, 5, 1, "999").unwrap();
+        server.join().unwrap();
+
+        assert_eq!(Outcome::TooHigh, outcome);
+    }
+
+    #[test]
+    fn test_submit_server_error() {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            request
+                .respond(tiny_http::Response::from_string("").with_status_code(500))
+                .unwrap();
+        });
+
+        assert!(submit(&base_url, "cookie", 2023, 5, 1, "1").is_err());
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_outcome_from_page() {
+        let cases = [
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "That's not the right answer. If you're stuck...",
+                Outcome::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait.",
+                Outcome::TooSoon,
+            ),
+            (
+                "You don't seem to be solving the right level.",
+                Outcome::WrongLevel,
+            ),
+            ("Something else entirely.", Outcome::Unknown),
+        ];
+        for (article, expected) in cases {
+            assert_eq!(expected, Outcome::from_page(&page(article)), "{article}");
+        }
+    }
+
+    #[test]
+    fn test_reject() {
+        let mut attempts = Attempts::default();
+        attempts.push(1, "100", Outcome::TooHigh);
+        attempts.push(1, "10", Outcome::TooLow);
+        attempts.push(1, "50", Outcome::Wrong);
+        attempts.push(1, "60", Outcome::TooSoon);
+
+        assert!(attempts.reject(1, "50").is_some());
+        assert!(attempts.reject(1, "100").is_some());
+        assert!(attempts.reject(1, "150").is_some());
+        assert!(attempts.reject(1, "10").is_some());
+        assert!(attempts.reject(1, "3").is_some());
+        assert_eq!(None, attempts.reject(1, "42"));
+        assert_eq!(None, attempts.reject(1, "60"));
+        assert_eq!(None, attempts.reject(1, "abc"));
+        assert_eq!(None, attempts.reject(2, "150"));
+
+        attempts.push(1, "42", Outcome::Correct);
+        assert!(attempts.reject(1, "43").is_some());
+    }
+
+    #[test]
+    fn test_attempts_roundtrip() {
+        let path = std::env::temp_dir()
+            .join(format!("submit-aoc-answer-{}", std::process::id()))
+            .join("attempts.json");
+
+        assert_eq!(Attempts::default(), Attempts::load(&path).unwrap());
+
+        let mut attempts = Attempts::default();
+        attempts.push(2, "46", Outcome::TooLow);
+        attempts.save(&path).unwrap();
+
+        assert_eq!(attempts, Attempts::load(&path).unwrap());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}